@@ -0,0 +1,68 @@
+//! Turns every annotated example (`src/dayN/<name>.answers` next to `<name>.txt`) into a test
+//! case per expected answer, included by `tests/examples.rs`.
+
+use core::solution::Part;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let src = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("..");
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    let mut days: Vec<(u8, PathBuf)> = fs::read_dir(&src)
+        .unwrap()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter_map(|path| {
+            let day = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix("day")?
+                .parse()
+                .ok()?;
+            Some((day, path))
+        })
+        .collect();
+    days.sort();
+
+    let mut tests = String::new();
+    for (day, dir) in days {
+        let examples = core::example::examples_in(&dir).unwrap_or_else(|error| panic!("{}", error));
+
+        for example in examples {
+            let name: String = example
+                .input
+                .file_stem()
+                .unwrap()
+                .to_string_lossy()
+                .chars()
+                .map(|chr| {
+                    if chr.is_ascii_alphanumeric() {
+                        chr
+                    } else {
+                        '_'
+                    }
+                })
+                .collect();
+
+            for (part, variant) in [(Part::One, "One"), (Part::Two, "Two")] {
+                if example.expected(part).is_none() {
+                    continue;
+                }
+
+                tests.push_str(&format!(
+                    "#[test]\nfn day{}_{}_part{}() {{\n    check({}, {:?}, Part::{});\n}}\n\n",
+                    day,
+                    name,
+                    part,
+                    day,
+                    example.input.display().to_string(),
+                    variant
+                ));
+            }
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
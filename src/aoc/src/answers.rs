@@ -0,0 +1,79 @@
+use core::Error;
+use core::input::InputKind;
+use core::solution::Part;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Recorded answers, one per line as `<day> <part> <real|example> <answer>`. Empty lines and lines
+/// starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct Answers {
+    entries: HashMap<(u8, Part, InputKind), String>,
+}
+
+impl Answers {
+    /// The `answers.txt` in the root of the workspace.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../answers.txt")
+    }
+
+    pub fn load(path: &Path) -> Result<Answers, Error> {
+        let text = fs::read_to_string(path).map_err(|e| Error::from(e).in_file(path))?;
+        Answers::parse(&text).map_err(|e| e.in_file(path))
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, Error> {
+        let mut entries = HashMap::new();
+
+        for (idx, line) in text.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let (day, part, kind, answer) =
+                core::scan!("{} {} {} {}", trimmed => u8, Part, InputKind, String)
+                    .map_err(|e| e.with_offset(line.len() - line.trim_start().len()))
+                    .map_err(|e| e.with_line(idx + 1, line))?;
+
+            if entries.insert((day, part, kind), answer).is_some() {
+                return Err(Error::new(format!(
+                    "Duplicate answer for day {} part {} ({} input)",
+                    day, part, kind
+                ))
+                .with_line(idx + 1, line));
+            }
+        }
+
+        Ok(Answers { entries })
+    }
+
+    pub fn get(&self, day: u8, part: Part, kind: InputKind) -> Option<&str> {
+        self.entries.get(&(day, part, kind)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Answers;
+    use core::input::InputKind;
+    use core::solution::Part;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse("# comment\n\n5 1 real 744\n5 2 example 14\n").unwrap();
+        assert_eq!(answers.get(5, Part::One, InputKind::Real), Some("744"));
+        assert_eq!(answers.get(5, Part::Two, InputKind::Example), Some("14"));
+        assert_eq!(answers.get(5, Part::Two, InputKind::Real), None);
+    }
+
+    #[test]
+    fn test_parse_answers_errors() {
+        let error = Answers::parse("5 3 real 744\n").unwrap_err();
+        assert_eq!(error.line(), Some(1));
+        assert_eq!(error.column(), Some(3));
+
+        assert!(Answers::parse("5 1 real 744\n5 1 real 745\n").is_err());
+    }
+}
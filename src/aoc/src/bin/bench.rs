@@ -0,0 +1,86 @@
+use aoc::cli::{BENCH_USAGE, BenchArgs};
+use aoc::history::History;
+use aoc::{history, registry};
+use core::Error;
+use core::bench::CountingAllocator;
+use core::solution::Part;
+use std::process::ExitCode;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn bench(args: &BenchArgs) -> Result<(), Error> {
+    let solution = registry::find(args.day).ok_or(format!("No solution for day {}", args.day))?;
+
+    let input = args.input.read(args.day)?;
+    let params = [input.params(Part::One)?, input.params(Part::Two)?];
+    let report = input.parse(|text| solution.bench_with(text, &args.config, &params))?;
+    println!("{}", report);
+
+    let label = history::input_label(&args.input);
+    let previous = History::load(&args.history)?;
+    History::append(&args.history, &History::entries_for(&report, &label))?;
+
+    if !args.compare {
+        return Ok(());
+    }
+
+    let comparisons = history::compare(&report, &label, &previous, args.baseline.as_deref());
+    if comparisons.is_empty() {
+        match args.baseline {
+            Some(ref commit) => {
+                println!(
+                    "\nNo run of day {} on {} at {} to compare with",
+                    args.day, label, commit
+                )
+            }
+            None => println!(
+                "\nNo earlier run of day {} on {} to compare with",
+                args.day, label
+            ),
+        }
+        return Ok(());
+    }
+
+    println!();
+    let mut regressions = 0;
+    for comparison in &comparisons {
+        let regression = comparison.is_regression(args.threshold);
+        regressions += regression as usize;
+
+        println!(
+            "{:<6} {:>12.2?} -> {:>12.2?} {:>+8.1}% (vs {}){}",
+            comparison.name,
+            comparison.baseline.median,
+            comparison.median,
+            comparison.change(),
+            comparison.baseline.commit,
+            if regression { "  REGRESSION" } else { "" }
+        );
+    }
+
+    if regressions > 0 {
+        return Err(Error::new(format!(
+            "{} measurements are more than {}% slower than the baseline",
+            regressions, args.threshold
+        )));
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = BenchArgs::parse(&args)
+        .map_err(|message| Error::new(format!("{}\n{}", message, BENCH_USAGE)))
+        .and_then(|args| bench(&args));
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use crate::answers::Answers;
+use crate::history::History;
+use crate::parallel::Limits;
+use core::Error;
+use core::bench::BenchConfig;
+use core::input::{Input, InputKind, InputLocator};
+use core::log::Filter;
+use core::solution::Part;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "Usage:
+  aoc run <day> [--part <1|2>] [--input <path>] [--example]
+  aoc all [--jobs <n>] [--timeout <seconds>] [--example]
+  aoc verify [<day>] [--answers <path>]
+  aoc watch <day> [--interval <ms>]
+  aoc fetch <day>
+  aoc submit <day> <1|2>
+  aoc new <day>
+
+Every command accepts --log <filter>, e.g. --log info,day4=trace, which overrides AOC_LOG.";
+
+pub const BENCH_USAGE: &str =
+    "Usage: bench <day> [--iterations <n>] [--warmup <n>] [--input <path>] [--example]
+             [--compare] [--baseline <commit>] [--threshold <percent>] [--history <path>]";
+
+/// Removes `--log <filter>` from anywhere in `args`, since it applies to every command.
+pub fn take_log(args: &mut Vec<String>) -> Result<Option<Filter>, String> {
+    let Some(idx) = args.iter().position(|arg| arg == "--log") else {
+        return Ok(None);
+    };
+    if idx + 1 >= args.len() {
+        return Err("Missing value for --log".to_string());
+    }
+
+    let spec: Vec<String> = args.drain(idx..idx + 2).collect();
+    spec[1]
+        .parse()
+        .map(Some)
+        .map_err(|e: Error| e.message().to_string())
+}
+
+pub struct InputArgs {
+    pub path: Option<PathBuf>,
+    pub kind: InputKind,
+}
+
+impl Default for InputArgs {
+    fn default() -> Self {
+        Self {
+            path: None,
+            kind: InputKind::Real,
+        }
+    }
+}
+
+impl InputArgs {
+    pub fn read(&self, day: u8) -> Result<Input, Error> {
+        InputLocator::new(day)
+            .with_path(self.path.clone())
+            .read(self.kind)
+    }
+}
+
+pub enum Command {
+    Run {
+        day: u8,
+        part: Option<Part>,
+        input: InputArgs,
+    },
+    All {
+        kind: InputKind,
+        limits: Limits,
+    },
+    Verify {
+        day: Option<u8>,
+        answers: PathBuf,
+    },
+    Watch {
+        day: u8,
+        interval: Duration,
+    },
+    Fetch {
+        day: u8,
+    },
+    Submit {
+        day: u8,
+        part: Part,
+    },
+    New {
+        day: u8,
+    },
+}
+
+impl Command {
+    pub fn parse(args: &[String]) -> Result<Command, String> {
+        let mut args = args.iter();
+
+        match args.next().map(String::as_str) {
+            Some("run") => {
+                let day = parse_day(args.next())?;
+                let mut part = None;
+                let mut input = InputArgs::default();
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--part" | "-p" => {
+                            let value = args.next().ok_or("Missing value for --part")?;
+                            part = Some(Part::parse(value)?);
+                        }
+                        "--input" | "-i" => {
+                            let value = args.next().ok_or("Missing value for --input")?;
+                            input.path = Some(PathBuf::from(value));
+                        }
+                        "--example" | "-e" => input.kind = InputKind::Example,
+                        _ => return Err(format!("Unknown argument: {}", arg)),
+                    }
+                }
+
+                Ok(Command::Run { day, part, input })
+            }
+            Some("all") => {
+                let mut kind = InputKind::Real;
+                let mut limits = Limits::default();
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--jobs" | "-j" => limits.jobs = parse_count(args.next(), arg)?.max(1),
+                        "--timeout" | "-t" => {
+                            let value = args.next().ok_or("Missing value for --timeout")?;
+                            let seconds = value
+                                .parse::<f64>()
+                                .ok()
+                                .filter(|seconds| seconds.is_finite() && *seconds > 0.0)
+                                .ok_or(format!("Invalid value for --timeout: {}", value))?;
+                            limits.timeout = Duration::from_secs_f64(seconds);
+                        }
+                        "--example" | "-e" => kind = InputKind::Example,
+                        _ => return Err(format!("Unknown argument: {}", arg)),
+                    }
+                }
+
+                Ok(Command::All { kind, limits })
+            }
+            Some("verify") => {
+                let mut day = None;
+                let mut answers = Answers::default_path();
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--answers" | "-a" => {
+                            let value = args.next().ok_or("Missing value for --answers")?;
+                            answers = PathBuf::from(value);
+                        }
+                        _ if day.is_none() && !arg.starts_with('-') => {
+                            day = Some(parse_day(Some(arg))?)
+                        }
+                        _ => return Err(format!("Unknown argument: {}", arg)),
+                    }
+                }
+
+                Ok(Command::Verify { day, answers })
+            }
+            Some("watch") => {
+                let day = parse_day(args.next())?;
+                let mut interval = Duration::from_millis(500);
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--interval" => {
+                            interval = Duration::from_millis(parse_count(args.next(), arg)? as u64)
+                        }
+                        _ => return Err(format!("Unknown argument: {}", arg)),
+                    }
+                }
+
+                Ok(Command::Watch { day, interval })
+            }
+            Some("fetch") => {
+                let day = parse_day(args.next())?;
+                if let Some(arg) = args.next() {
+                    return Err(format!("Unknown argument: {}", arg));
+                }
+
+                Ok(Command::Fetch { day })
+            }
+            Some("submit") => {
+                let day = parse_day(args.next())?;
+                let part = Part::parse(args.next().ok_or("Missing part")?)?;
+                if let Some(arg) = args.next() {
+                    return Err(format!("Unknown argument: {}", arg));
+                }
+
+                Ok(Command::Submit { day, part })
+            }
+            Some("new") => {
+                let day = parse_day(args.next())?;
+                if let Some(arg) = args.next() {
+                    return Err(format!("Unknown argument: {}", arg));
+                }
+
+                Ok(Command::New { day })
+            }
+            Some(command) => Err(format!("Unknown command: {}", command)),
+            None => Err("No command given".to_string()),
+        }
+    }
+}
+
+pub struct BenchArgs {
+    pub day: u8,
+    pub config: BenchConfig,
+    pub input: InputArgs,
+    pub history: PathBuf,
+    /// Compare with the previous run, or with the latest run of `baseline` if set.
+    pub compare: bool,
+    pub baseline: Option<String>,
+    /// Slowdown in percent above which a comparison counts as a regression.
+    pub threshold: f64,
+}
+
+impl BenchArgs {
+    pub fn parse(args: &[String]) -> Result<BenchArgs, String> {
+        let mut args = args.iter();
+        let day = parse_day(args.next())?;
+        let mut config = BenchConfig::default();
+        let mut input = InputArgs::default();
+        let mut history = History::default_path();
+        let mut compare = false;
+        let mut baseline = None;
+        let mut threshold = 10.0;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--compare" | "-c" => compare = true,
+                "--baseline" | "-b" => {
+                    let value = args.next().ok_or("Missing value for --baseline")?;
+                    baseline = Some(value.clone());
+                    compare = true;
+                }
+                "--threshold" | "-t" => {
+                    let value = args.next().ok_or("Missing value for --threshold")?;
+                    threshold = value
+                        .parse::<f64>()
+                        .map_err(|_| format!("Invalid value for --threshold: {}", value))?;
+                }
+                "--history" => {
+                    let value = args.next().ok_or("Missing value for --history")?;
+                    history = PathBuf::from(value);
+                }
+                "--iterations" | "-n" => config.iterations = parse_count(args.next(), arg)?,
+                "--warmup" | "-w" => config.warmup = parse_count(args.next(), arg)?,
+                "--input" | "-i" => {
+                    let value = args.next().ok_or("Missing value for --input")?;
+                    input.path = Some(PathBuf::from(value));
+                }
+                "--example" | "-e" => input.kind = InputKind::Example,
+                _ => return Err(format!("Unknown argument: {}", arg)),
+            }
+        }
+
+        Ok(BenchArgs {
+            day,
+            config,
+            input,
+            history,
+            compare,
+            baseline,
+            threshold,
+        })
+    }
+}
+
+fn parse_count(arg: Option<&String>, flag: &str) -> Result<usize, String> {
+    let arg = arg.ok_or(format!("Missing value for {}", flag))?;
+    arg.parse::<usize>()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, arg))
+}
+
+fn parse_day(arg: Option<&String>) -> Result<u8, String> {
+    let arg = arg.ok_or("Missing day")?;
+    arg.parse::<u8>()
+        .map_err(|_| format!("Invalid day: {}", arg))
+}
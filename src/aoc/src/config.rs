@@ -0,0 +1,144 @@
+use core::Error;
+use core::input::INPUT_DIR_VAR;
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const YEAR_VAR: &str = "AOC_YEAR";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u16 = 2025;
+
+/// Settings for talking to the puzzle server.
+///
+/// Read from `aoc.conf` in the root of the workspace, with `key = value` lines for `session`,
+/// `base_url` and `year`. The environment variables [`SESSION_VAR`], [`BASE_URL_VAR`] and
+/// [`YEAR_VAR`] take precedence over the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub year: u16,
+    /// Directory laid out like `src/`, where inputs are cached as `dayN/input.txt`.
+    pub input_dir: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            year: DEFAULT_YEAR,
+            input_dir: Path::new(env!("CARGO_MANIFEST_DIR")).join(".."),
+        }
+    }
+}
+
+impl Config {
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../aoc.conf")
+    }
+
+    /// Loads the config file, if there is one, and applies the environment on top.
+    pub fn load(path: &Path) -> Result<Config, Error> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(text) => Config::parse(&text).map_err(|e| e.in_file(path))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(Error::from(e).in_file(path)),
+        };
+
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VAR) {
+            config.base_url = base_url;
+        }
+        if let Ok(year) = env::var(YEAR_VAR) {
+            config.year = year
+                .parse()
+                .map_err(|_| Error::new(format!("Invalid {}: {}", YEAR_VAR, year)))?;
+        }
+        if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+            config.input_dir = PathBuf::from(dir);
+        }
+
+        Ok(config)
+    }
+
+    pub fn parse(text: &str) -> Result<Config, Error> {
+        let mut config = Config::default();
+
+        for (idx, line) in text.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = trimmed
+                .split_once('=')
+                .map(|(key, value)| (key.trim(), value.trim()))
+                .ok_or_else(|| Error::new("Expected key = value").with_line(idx + 1, line))?;
+
+            match key {
+                "session" => config.session = Some(value.to_string()),
+                "base_url" => config.base_url = value.to_string(),
+                "year" => {
+                    config.year = value.parse().map_err(|_| {
+                        Error::at_slice(format!("Invalid year: {}", value), line, value)
+                            .with_line(idx + 1, line)
+                    })?
+                }
+                _ => {
+                    return Err(Error::at_slice(format!("Unknown key: {}", key), line, key)
+                        .with_line(idx + 1, line));
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn session(&self) -> Result<&str, Error> {
+        self.session.as_deref().ok_or_else(|| {
+            Error::new(format!(
+                "No session token configured, set {} or add `session = <token>` to {}",
+                SESSION_VAR,
+                Config::default_path().display()
+            ))
+        })
+    }
+
+    pub fn day_url(&self, day: u8) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url.trim_end_matches('/'),
+            self.year,
+            day
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+
+    #[test]
+    fn test_parse_config() {
+        let config = Config::parse(
+            "# comment\nsession = abc\nbase_url = http://127.0.0.1:8080/\nyear=2024\n",
+        )
+        .unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.day_url(5), "http://127.0.0.1:8080/2024/day/5");
+    }
+
+    #[test]
+    fn test_parse_config_unknown_key() {
+        let error = Config::parse("sesion = abc\n").unwrap_err();
+        assert_eq!(error.line(), Some(1));
+        assert_eq!(error.column(), Some(1));
+    }
+}
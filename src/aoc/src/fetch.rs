@@ -0,0 +1,118 @@
+use crate::config::Config;
+use crate::http;
+use core::Error;
+use std::fs;
+use std::path::PathBuf;
+
+pub const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+pub fn input_path(config: &Config, day: u8) -> PathBuf {
+    config
+        .input_dir
+        .join(format!("day{}", day))
+        .join("input.txt")
+}
+
+/// Downloads the input of `day` into the cache, unless it is already there. Inputs never change,
+/// so a cached file is never fetched again; delete it to force a download.
+pub fn fetch(config: &Config, day: u8) -> Result<Fetched, Error> {
+    let path = input_path(config, day);
+    if path.is_file() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let cookie = format!("session={}", config.session()?);
+    let url = format!("{}/input", config.day_url(day));
+    let response = http::get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])?;
+
+    if !response.is_success() {
+        return Err(Error::new(format!(
+            "Could not download input for day {}: HTTP {}: {}",
+            day,
+            response.status,
+            response.body.trim()
+        )));
+    }
+    if response.body.trim().is_empty() {
+        return Err(Error::new(format!(
+            "Downloaded input for day {} is empty",
+            day
+        )));
+    }
+
+    // Written next to the target and renamed, so an interrupted download never looks cached
+    let partial = path.with_extension("txt.partial");
+    let write = |path: &PathBuf| -> Result<(), std::io::Error> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&partial, &response.body)?;
+        fs::rename(&partial, path)
+    };
+    write(&path).map_err(|e| Error::from(e).in_file(&path))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+    use crate::fetch::{Fetched, fetch};
+    use crate::mock::MockServer;
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir() -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        env::temp_dir().join(format!("aoc-fetch-{}-{}", std::process::id(), nanos))
+    }
+
+    #[test]
+    fn test_fetch_downloads_once() {
+        let server = MockServer::start(vec![(200, "3-5\n10-14\n\n1\n")]);
+        let config = Config {
+            session: Some("secret".to_string()),
+            base_url: server.url.clone(),
+            year: 2025,
+            input_dir: temp_dir(),
+        };
+
+        let Fetched::Downloaded(path) = fetch(&config, 5).unwrap() else {
+            panic!("expected a download");
+        };
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3-5\n10-14\n\n1\n");
+        assert!(matches!(fetch(&config, 5).unwrap(), Fetched::Cached(_)));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2025/day/5/input HTTP/1.1"));
+        assert!(requests[0].contains("Cookie: session=secret"));
+
+        fs::remove_dir_all(&config.input_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_error_is_not_cached() {
+        let server = MockServer::start(vec![(400, "Please log in")]);
+        let config = Config {
+            session: Some("expired".to_string()),
+            base_url: server.url.clone(),
+            year: 2025,
+            input_dir: temp_dir(),
+        };
+
+        let error = fetch(&config, 1).err().unwrap();
+        assert!(error.message().contains("HTTP 400: Please log in"));
+        assert!(!config.input_dir.join("day1/input.txt").exists());
+    }
+}
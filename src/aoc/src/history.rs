@@ -0,0 +1,360 @@
+use crate::cli::InputArgs;
+use core::Error;
+use core::bench::BenchReport;
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// One timed measurement, stored as a tab separated line
+/// `<unix time> <commit> <day> <input> <iterations> <name> <median in ns>`.
+///
+/// Lines written before the input and iteration count were recorded only have
+/// `<unix time> <commit> <day> <name> <median in ns>`. They are read with an unknown input and
+/// never used as a baseline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub timestamp: u64,
+    pub commit: String,
+    pub day: u8,
+    /// Which input was measured, see [`input_label`].
+    pub input: String,
+    pub iterations: usize,
+    /// `parse`, `part1` or `part2`.
+    pub name: String,
+    pub median: Duration,
+}
+
+/// Input label of entries that predate it.
+const UNKNOWN_INPUT: &str = "unknown";
+
+impl Entry {
+    fn parse(line: &str) -> Result<Entry, Error> {
+        if line.split('\t').count() == 5 {
+            let (timestamp, commit, day, name, median) =
+                core::scan!("{}\t{}\t{}\t{}\t{}", line => u64, String, u8, String, u64)?;
+
+            return Ok(Entry {
+                timestamp,
+                commit,
+                day,
+                input: UNKNOWN_INPUT.to_string(),
+                iterations: 0,
+                name,
+                median: Duration::from_nanos(median),
+            });
+        }
+
+        let (timestamp, commit, day, input, iterations, name, median) = core::scan!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            line => u64, String, u8, String, usize, String, u64
+        )?;
+
+        Ok(Entry {
+            timestamp,
+            commit,
+            day,
+            input,
+            iterations,
+            name,
+            median: Duration::from_nanos(median),
+        })
+    }
+
+    /// Whether this entry measured the same thing as a run of `day` on `input` with
+    /// `iterations`, so their medians can be compared.
+    fn measures(&self, day: u8, input: &str, iterations: usize) -> bool {
+        self.day == day
+            && self.input != UNKNOWN_INPUT
+            && self.input == input
+            && self.iterations == iterations
+    }
+}
+
+/// Which input `--input` and `--example` select: `real`, `example`, or the explicit path, which
+/// is `-` for stdin.
+pub fn input_label(input: &InputArgs) -> String {
+    match input.path {
+        Some(ref path) => path.display().to_string().replace('\t', " "),
+        None => input.kind.to_string(),
+    }
+}
+
+/// Local record of benchmark results, used to spot performance regressions between commits.
+#[derive(Debug, Default)]
+pub struct History {
+    pub entries: Vec<Entry>,
+}
+
+impl History {
+    /// `bench_history.tsv` in the root of the workspace.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../bench_history.tsv")
+    }
+
+    /// Loads the history, which is empty if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<History, Error> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(History::default()),
+            Err(e) => return Err(Error::from(e).in_file(path)),
+        };
+
+        let entries = core::parse_lines(&text, Entry::parse).map_err(|e| e.in_file(path))?;
+        Ok(History { entries })
+    }
+
+    /// Most recent entry for `name` of a run like `report`, i.e. the same day, input and
+    /// iteration count, restricted to `commit` if given.
+    pub fn baseline(
+        &self,
+        report: &BenchReport,
+        input: &str,
+        name: &str,
+        commit: Option<&str>,
+    ) -> Option<&Entry> {
+        self.entries.iter().rev().find(|entry| {
+            entry.measures(report.day, input, report.config.iterations)
+                && entry.name == name
+                && commit.is_none_or(|commit| entry.commit.starts_with(commit))
+        })
+    }
+
+    /// Entries for every measurement in `report` of `input`, tagged with the current time and
+    /// commit.
+    pub fn entries_for(report: &BenchReport, input: &str) -> Vec<Entry> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs());
+        let commit = current_commit();
+
+        report
+            .measurements
+            .iter()
+            .map(|measurement| Entry {
+                timestamp,
+                commit: commit.clone(),
+                day: report.day,
+                input: input.to_string(),
+                iterations: report.config.iterations,
+                name: measurement.name.to_string(),
+                median: measurement.stats.median,
+            })
+            .collect()
+    }
+
+    pub fn append(path: &Path, entries: &[Entry]) -> Result<(), Error> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| Error::from(e).in_file(path))?;
+
+        for entry in entries {
+            writeln!(
+                file,
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                entry.timestamp,
+                entry.commit,
+                entry.day,
+                entry.input,
+                entry.iterations,
+                entry.name,
+                entry.median.as_nanos()
+            )
+            .map_err(|e| Error::from(e).in_file(path))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Short hash of `HEAD`, with `-dirty` appended if there are uncommitted changes.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{}-dirty", commit)
+        }
+        Some(commit) => commit,
+        None => "unknown".to_string(),
+    }
+}
+
+/// Median of a new run next to the one it is compared with.
+pub struct Comparison {
+    pub name: String,
+    pub baseline: Entry,
+    pub median: Duration,
+}
+
+impl Comparison {
+    /// Relative change in percent, positive if the new run is slower.
+    pub fn change(&self) -> f64 {
+        let old = self.baseline.median.as_secs_f64();
+        let new = self.median.as_secs_f64();
+        if old == 0.0 {
+            return 0.0;
+        }
+        (new / old - 1.0) * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Compares every measurement of `report` on `input` with its baseline in `history`, skipping
+/// those without.
+pub fn compare(
+    report: &BenchReport,
+    input: &str,
+    history: &History,
+    commit: Option<&str>,
+) -> Vec<Comparison> {
+    report
+        .measurements
+        .iter()
+        .filter_map(|measurement| {
+            let baseline = history.baseline(report, input, measurement.name, commit)?;
+            Some(Comparison {
+                name: measurement.name.to_string(),
+                baseline: baseline.clone(),
+                median: measurement.stats.median,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::history::{Comparison, Entry, History};
+    use core::bench::{BenchConfig, BenchReport};
+    use std::time::Duration;
+
+    fn entry(commit: &str, name: &str, micros: u64) -> Entry {
+        Entry {
+            timestamp: 0,
+            commit: commit.to_string(),
+            day: 8,
+            input: "real".to_string(),
+            iterations: 50,
+            name: name.to_string(),
+            median: Duration::from_micros(micros),
+        }
+    }
+
+    fn report(day: u8, iterations: usize) -> BenchReport {
+        BenchReport {
+            day,
+            config: BenchConfig {
+                warmup: 5,
+                iterations,
+            },
+            measurements: vec![],
+        }
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        let entry = Entry::parse("1700000000\tabc1234\t8\texample\t50\tpart1\t15950000").unwrap();
+        assert_eq!(entry.commit, "abc1234");
+        assert_eq!(entry.input, "example");
+        assert_eq!(entry.iterations, 50);
+        assert_eq!(entry.median, Duration::from_micros(15950));
+    }
+
+    #[test]
+    fn test_parse_entry_without_input() {
+        let entry = Entry::parse("1700000000\tabc1234\t8\tpart1\t15950000").unwrap();
+        assert_eq!(entry.name, "part1");
+        let history = History {
+            entries: vec![entry],
+        };
+        assert!(
+            history
+                .baseline(&report(8, 50), "real", "part1", None)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_baseline_is_latest_matching() {
+        let history = History {
+            entries: vec![
+                entry("aaa", "part1", 10),
+                entry("bbb", "part1", 20),
+                entry("bbb", "part2", 30),
+            ],
+        };
+
+        let day8 = report(8, 50);
+        assert_eq!(
+            history
+                .baseline(&day8, "real", "part1", None)
+                .unwrap()
+                .commit,
+            "bbb"
+        );
+        assert_eq!(
+            history
+                .baseline(&day8, "real", "part1", Some("aaa"))
+                .unwrap()
+                .median,
+            Duration::from_micros(10)
+        );
+        assert!(
+            history
+                .baseline(&report(9, 50), "real", "part1", None)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_baseline_requires_same_input() {
+        let mut example = entry("ccc", "part1", 3);
+        example.input = "example".to_string();
+        let history = History {
+            entries: vec![entry("aaa", "part1", 12910), example],
+        };
+
+        let real = history.baseline(&report(8, 50), "real", "part1", None);
+        assert_eq!(real.unwrap().commit, "aaa");
+        let example = history.baseline(&report(8, 50), "example", "part1", None);
+        assert_eq!(example.unwrap().commit, "ccc");
+        assert!(
+            history
+                .baseline(&report(8, 50), "/tmp/other.txt", "part1", None)
+                .is_none()
+        );
+        assert!(
+            history
+                .baseline(&report(8, 200), "real", "part1", None)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_regression_threshold() {
+        let comparison = Comparison {
+            name: "part1".to_string(),
+            baseline: entry("aaa", "part1", 100),
+            median: Duration::from_micros(125),
+        };
+
+        assert!((comparison.change() - 25.0).abs() < 1e-9);
+        assert!(comparison.is_regression(10.0));
+        assert!(!comparison.is_regression(30.0));
+    }
+}
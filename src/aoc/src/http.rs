@@ -0,0 +1,254 @@
+use core::Error;
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// How long connecting, and then each read or write, may take before a request is given up.
+pub const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+pub fn get(url: &str, headers: &[(&str, &str)]) -> Result<Response, Error> {
+    request("GET", url, headers, None)
+}
+
+/// Sends a request and waits for the full response.
+///
+/// Plain `http://` is spoken directly, which is all a local test server needs. The standard
+/// library has no TLS, so `https://` requests are handed to the `curl` command line tool.
+pub fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, Error> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        request_plain(method, rest, headers, body)
+            .map_err(|e| e.context(format!("{} {}", method, url)))
+    } else if url.starts_with("https://") {
+        request_curl(method, url, headers, body)
+            .map_err(|e| e.context(format!("{} {}", method, url)))
+    } else {
+        Err(Error::new(format!(
+            "Unsupported URL: {}, expected http:// or https://",
+            url
+        )))
+    }
+}
+
+fn request_plain(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, Error> {
+    let (authority, path) = match url.find('/') {
+        Some(idx) => (&url[..idx], &url[idx..]),
+        None => (url, "/"),
+    };
+    let address = match authority.contains(':') {
+        true => authority.to_string(),
+        false => format!("{}:80", authority),
+    };
+
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+        method, path, authority
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    if let Some(body) = body {
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(body.unwrap_or(""));
+
+    let mut stream = connect(&address)?;
+    stream.write_all(request.as_bytes())?;
+
+    let mut response: Vec<u8> = vec![];
+    stream.read_to_end(&mut response)?;
+    parse_response(&String::from_utf8_lossy(&response))
+}
+
+fn connect(address: &str) -> Result<TcpStream, Error> {
+    let mut last_error = None;
+    for address in address.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&address, TIMEOUT) {
+            Ok(stream) => {
+                stream.set_read_timeout(Some(TIMEOUT))?;
+                stream.set_write_timeout(Some(TIMEOUT))?;
+                return Ok(stream);
+            }
+            Err(error) => last_error = Some(error),
+        }
+    }
+
+    Err(last_error.map_or_else(
+        || Error::new(format!("Could not resolve {}", address)),
+        Error::from,
+    ))
+}
+
+fn parse_response(response: &str) -> Result<Response, Error> {
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or("Malformed HTTP response: missing end of headers")?;
+    let mut lines = head.lines();
+
+    let status_line = lines.next().unwrap_or_default();
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(|| format!("Malformed HTTP status line: {}", status_line))?;
+
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.eq_ignore_ascii_case("transfer-encoding") && value.trim() == "chunked"
+        })
+    });
+
+    let body = match chunked {
+        true => decode_chunked(body)?,
+        false => body.to_string(),
+    };
+
+    Ok(Response { status, body })
+}
+
+fn decode_chunked(mut body: &str) -> Result<String, Error> {
+    let mut decoded = String::new();
+
+    loop {
+        let (size, rest) = body
+            .split_once("\r\n")
+            .ok_or("Malformed chunked HTTP body")?;
+        let size = usize::from_str_radix(size.split(';').next().unwrap_or("").trim(), 16)
+            .map_err(|_| format!("Malformed chunk size: {}", size))?;
+        if size == 0 {
+            return Ok(decoded);
+        }
+
+        let chunk = rest.get(..size).ok_or("Truncated chunked HTTP body")?;
+        decoded.push_str(chunk);
+        body = rest[size..].trim_start_matches("\r\n");
+    }
+}
+
+/// Headers and body are handed to curl as a config file on stdin, so the session cookie does
+/// not show up in the argument list of the process.
+fn request_curl(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, Error> {
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--request", method])
+        .args(["--write-out", "\n%{http_code}"])
+        .args(["--connect-timeout", &TIMEOUT.as_secs().to_string()])
+        .args(["--speed-time", &TIMEOUT.as_secs().to_string()])
+        .args(["--config", "-"])
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Could not run curl, which is needed for https: {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(curl_config(headers, body).as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+
+    if !output.status.success() {
+        return Err(Error::new(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout
+        .rsplit_once('\n')
+        .ok_or("Unexpected output from curl")?;
+    let status = status
+        .trim()
+        .parse::<u16>()
+        .map_err(|_| format!("Unexpected status from curl: {}", status))?;
+
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+fn curl_config(headers: &[(&str, &str)], body: Option<&str>) -> String {
+    let quote = |value: &str| {
+        let mut quoted = String::from("\"");
+        for chr in value.chars() {
+            match chr {
+                '"' => quoted.push_str("\\\""),
+                '\\' => quoted.push_str("\\\\"),
+                '\n' => quoted.push_str("\\n"),
+                '\r' => quoted.push_str("\\r"),
+                '\t' => quoted.push_str("\\t"),
+                chr => quoted.push(chr),
+            }
+        }
+        quoted.push('"');
+        quoted
+    };
+
+    let mut config = String::new();
+    for (name, value) in headers {
+        config.push_str(&format!(
+            "header = {}\n",
+            quote(&format!("{}: {}", name, value))
+        ));
+    }
+    if let Some(body) = body {
+        config.push_str(&format!("data-binary = {}\n", quote(body)));
+    }
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::{curl_config, parse_response};
+
+    #[test]
+    fn test_parse_response() {
+        let response = parse_response("HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n1-2\n").unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "1-2\n");
+    }
+
+    #[test]
+    fn test_parse_chunked_response() {
+        let response = parse_response(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n3-5\n\r\n3\r\n10\n\r\n0\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(response.body, "3-5\n10\n");
+    }
+
+    #[test]
+    fn test_curl_config() {
+        let config = curl_config(&[("Cookie", "session=abc")], Some("answer=\"1\\2\"\n"));
+        assert_eq!(
+            config,
+            "header = \"Cookie: session=abc\"\ndata-binary = \"answer=\\\"1\\\\2\\\"\\n\"\n"
+        );
+    }
+}
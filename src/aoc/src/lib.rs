@@ -0,0 +1,15 @@
+pub mod answers;
+pub mod cli;
+pub mod config;
+pub mod fetch;
+pub mod history;
+pub mod http;
+pub mod parallel;
+pub mod registry;
+pub mod scaffold;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+
+#[cfg(test)]
+mod mock;
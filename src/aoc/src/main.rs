@@ -0,0 +1,160 @@
+use aoc::answers::Answers;
+use aoc::cli::{Command, InputArgs};
+use aoc::config::Config;
+use aoc::fetch::Fetched;
+use aoc::parallel::Limits;
+use aoc::submit::{SubmissionLog, Verdict};
+use aoc::{cli, fetch, parallel, registry, scaffold, submit, verify, watch};
+use core::Error;
+use core::input::InputKind;
+use core::solution::Part;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+fn run(day: u8, part: Option<Part>, input: &InputArgs) -> Result<(), Error> {
+    let solution = registry::find(day).ok_or(format!("No solution for day {}", day))?;
+
+    let input = input.read(day)?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    for part in parts {
+        let params = input.params(part)?;
+        let answer = input.parse(|text| solution.solve_with(text, part, &params))?;
+        println!("Day {} Part {}: {}", day, part, answer);
+    }
+
+    Ok(())
+}
+
+fn all(kind: InputKind, limits: &Limits) -> Result<(), Error> {
+    let runs = parallel::run_all(registry::solutions(), kind, limits);
+
+    for run in &runs {
+        println!("{}", run);
+    }
+
+    let failed = runs.iter().filter(|run| run.is_failure()).count();
+    println!("\n{} days solved, {} failed", runs.len() - failed, failed);
+
+    if failed > 0 {
+        return Err(Error::new(format!("{} days failed", failed)));
+    }
+
+    Ok(())
+}
+
+fn verify(day: Option<u8>, answers: &Path) -> Result<(), Error> {
+    let answers = Answers::load(answers)?;
+
+    let solutions = match day {
+        Some(day) => vec![registry::find(day).ok_or(format!("No solution for day {}", day))?],
+        None => registry::solutions(),
+    };
+
+    let checks: Vec<verify::Check> = solutions
+        .into_iter()
+        .flat_map(|solution| verify::verify(solution, &answers))
+        .collect();
+
+    for check in &checks {
+        println!("{}", check);
+    }
+
+    let count = |f: fn(&verify::Status) -> bool| checks.iter().filter(|c| f(&c.status)).count();
+    let passed = count(|status| matches!(status, verify::Status::Pass));
+    let failed = count(verify::Status::is_failure);
+    let missing = count(|status| matches!(status, verify::Status::Missing));
+
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+
+    if failed > 0 {
+        return Err(Error::new(format!("{} checks failed", failed)));
+    }
+
+    Ok(())
+}
+
+fn watch(day: u8, interval: Duration) -> Result<(), Error> {
+    registry::find(day).ok_or(format!("No solution for day {}", day))?;
+    watch::watch(&scaffold::workspace_root(), day, interval)
+}
+
+fn fetch(day: u8) -> Result<(), Error> {
+    let config = Config::load(&Config::default_path())?;
+
+    match fetch::fetch(&config, day)? {
+        Fetched::Cached(path) => println!("Input of day {} is cached at {}", day, path.display()),
+        Fetched::Downloaded(path) => {
+            println!("Downloaded input of day {} to {}", day, path.display())
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(day: u8, part: Part) -> Result<(), Error> {
+    let config = Config::load(&Config::default_path())?;
+    let solution = registry::find(day).ok_or(format!("No solution for day {}", day))?;
+
+    let input = InputArgs::default().read(day)?;
+    let answer = input.parse(|text| solution.solve(text, part))?;
+    println!("Day {} Part {}: {}", day, part, answer);
+
+    let verdict = submit::submit(&config, &SubmissionLog::default_path(), day, part, &answer)?;
+    println!("{}", verdict);
+
+    match verdict {
+        Verdict::Correct | Verdict::AlreadySolved => Ok(()),
+        _ => Err(Error::new(format!("Answer {} was not accepted", answer))),
+    }
+}
+
+fn new(day: u8) -> Result<(), Error> {
+    let dir = scaffold::scaffold(&scaffold::workspace_root(), day)?;
+
+    println!("Created {}", dir.display());
+    println!(
+        "Paste the example into input_example.txt and run `aoc fetch {}` for the real input",
+        day
+    );
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    let command = cli::take_log(&mut args).and_then(|filter| {
+        if let Some(filter) = filter {
+            core::log::init(filter);
+        }
+        Command::parse(&args)
+    });
+
+    let result = match command {
+        Ok(Command::Run { day, part, input }) => run(day, part, &input),
+        Ok(Command::All { kind, limits }) => all(kind, &limits),
+        Ok(Command::Verify { day, answers }) => verify(day, &answers),
+        Ok(Command::Watch { day, interval }) => watch(day, interval),
+        Ok(Command::Fetch { day }) => fetch(day),
+        Ok(Command::Submit { day, part }) => submit(day, part),
+        Ok(Command::New { day }) => new(day),
+        Err(message) => Err(Error::new(format!("{}\n{}", message, cli::USAGE))),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use crate::verify;
+use core::Error;
+use core::input::{InputKind, InputLocator};
+use core::solution::{Params, Part, Runner};
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::sync::Mutex;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Number of days solved at the same time.
+    pub jobs: usize,
+    /// Time a day gets for parsing and both parts together.
+    pub timeout: Duration,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            jobs: thread::available_parallelism().map_or(4, |jobs| jobs.get()),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+pub enum Outcome {
+    Solved(String),
+    Failed(Error),
+    /// The time ran out before the part finished.
+    TimedOut,
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Solved(_))
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Solved(answer) => write!(f, "{}", answer),
+            Outcome::Failed(error) => write!(f, "ERROR   {}", error.message()),
+            Outcome::TimedOut => write!(f, "TIMEOUT"),
+        }
+    }
+}
+
+pub struct DayRun {
+    pub day: u8,
+    pub parts: Vec<(Part, Outcome)>,
+    pub elapsed: Duration,
+}
+
+impl DayRun {
+    pub fn is_failure(&self) -> bool {
+        self.parts.iter().any(|(_, outcome)| outcome.is_failure())
+    }
+}
+
+impl Display for DayRun {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (idx, (part, outcome)) in self.parts.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "Day {} Part {}: {}", self.day, part, outcome)?;
+        }
+        write!(f, "\nDay {} took {:?}", self.day, self.elapsed)
+    }
+}
+
+/// Reads the input of `solution` and solves both parts on a thread of their own, giving up on
+/// whatever has not finished when `timeout` runs out.
+///
+/// A thread can not be stopped from the outside, so a day that times out is only abandoned: it
+/// keeps running and using a CPU in the background until it finishes or the process exits. The
+/// slot in the pool moves on to the next day, so after timeouts more than `jobs` days may be
+/// computing at once.
+pub fn run_day(solution: &'static dyn Runner, kind: InputKind, timeout: Duration) -> DayRun {
+    let day = solution.day();
+    let start = Instant::now();
+    let result = |parts: Vec<(Part, Outcome)>| DayRun {
+        day,
+        parts,
+        elapsed: start.elapsed(),
+    };
+
+    let prepared = InputLocator::new(day)
+        .read(kind)
+        .and_then(|input| Ok((params(day, kind)?, input)));
+    let (params, input) = match prepared {
+        Ok(prepared) => prepared,
+        Err(error) => {
+            return result(
+                Part::ALL
+                    .iter()
+                    .map(|&part| (part, Outcome::Failed(error.clone())))
+                    .collect(),
+            );
+        }
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for part in Part::ALL {
+            let params = &params[part.number() as usize - 1];
+            let answer = input.parse(|text| verify::solve_catching(solution, text, part, params));
+            if sender.send((part, answer)).is_err() {
+                return;
+            }
+        }
+    });
+
+    let deadline = start + timeout;
+    let parts = Part::ALL
+        .iter()
+        .map(|&part| {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let outcome = match receiver.recv_timeout(remaining) {
+                Ok((_, Ok(answer))) => Outcome::Solved(answer),
+                Ok((_, Err(error))) => Outcome::Failed(error),
+                Err(_) => Outcome::TimedOut,
+            };
+            (part, outcome)
+        })
+        .collect();
+
+    result(parts)
+}
+
+/// Parameters of both parts the example of `day` is annotated with, none for the real input.
+fn params(day: u8, kind: InputKind) -> Result<[Params; 2], Error> {
+    match (kind, verify::annotation(day)?) {
+        (InputKind::Example, Some(example)) => Ok(Part::ALL.map(|part| example.params(part))),
+        _ => Ok([Params::new(), Params::new()]),
+    }
+}
+
+/// Runs every solution on a pool of `limits.jobs` threads, returning the runs ordered by day.
+///
+/// A panic is reported as a failure of the part that panicked, with the message taken from the
+/// caught payload. The panic hook is left alone, so the default hook still prints it to stderr.
+/// Timed out days are abandoned, not stopped, see [`run_day`].
+pub fn run_all(
+    solutions: Vec<&'static dyn Runner>,
+    kind: InputKind,
+    limits: &Limits,
+) -> Vec<DayRun> {
+    let queue = Mutex::new(VecDeque::from(solutions));
+    let runs = Mutex::new(vec![]);
+
+    thread::scope(|scope| {
+        for _ in 0..limits.jobs.max(1) {
+            scope.spawn(|| {
+                while let Some(solution) = queue.lock().unwrap().pop_front() {
+                    let run = run_day(solution, kind, limits.timeout);
+                    runs.lock().unwrap().push(run);
+                }
+            });
+        }
+    });
+
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|run| run.day);
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parallel::{Limits, Outcome, run_all};
+    use core::Error;
+    use core::input::InputKind;
+    use core::solution::{Runner, Solution};
+    use std::thread;
+    use std::time::Duration;
+
+    struct Slow;
+
+    impl Solution for Slow {
+        const DAY: u8 = 1;
+        type Input = ();
+
+        fn parse(_: &str) -> Result<Self::Input, Error> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Input) -> impl std::fmt::Display {
+            1
+        }
+
+        fn part2(_: &Self::Input) -> impl std::fmt::Display {
+            thread::sleep(Duration::from_secs(10));
+            2
+        }
+    }
+
+    struct Panicking;
+
+    impl Solution for Panicking {
+        const DAY: u8 = 2;
+        type Input = ();
+
+        fn parse(_: &str) -> Result<Self::Input, Error> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Input) -> impl std::fmt::Display {
+            if true {
+                panic!("Hit wrong element, we are doomed!");
+            }
+            1
+        }
+
+        fn part2(_: &Self::Input) -> impl std::fmt::Display {
+            2
+        }
+    }
+
+    #[test]
+    fn test_failures_are_isolated() {
+        let solutions: Vec<&'static dyn Runner> = vec![&Panicking, &Slow, &day5::Day5];
+        let limits = Limits {
+            jobs: 2,
+            timeout: Duration::from_millis(200),
+        };
+
+        let runs = run_all(solutions, InputKind::Example, &limits);
+        let outcomes: Vec<Vec<String>> = runs
+            .iter()
+            .map(|run| {
+                run.parts
+                    .iter()
+                    .map(|(_, outcome)| outcome.to_string())
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(outcomes[0], vec!["1", "TIMEOUT"]);
+        assert_eq!(
+            outcomes[1],
+            vec!["ERROR   panicked: Hit wrong element, we are doomed!", "2"]
+        );
+        assert_eq!(outcomes[2], vec!["3", "14"]);
+        assert!(matches!(runs[0].parts[1].1, Outcome::TimedOut));
+        assert!(!runs[2].is_failure());
+    }
+}
@@ -0,0 +1,21 @@
+use core::solution::Runner;
+
+pub fn solutions() -> Vec<&'static dyn Runner> {
+    vec![
+        &day1::Day1,
+        &day2::Day2,
+        &day3::Day3,
+        &day4::Day4,
+        &day5::Day5,
+        &day6::Day6,
+        &day7::Day7,
+        &day8::Day8,
+        &day9::Day9,
+    ]
+}
+
+pub fn find(day: u8) -> Option<&'static dyn Runner> {
+    solutions()
+        .into_iter()
+        .find(|solution| solution.day() == day)
+}
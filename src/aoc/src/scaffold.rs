@@ -0,0 +1,141 @@
+use core::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.template");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.template");
+
+/// Root of the workspace this binary was built from.
+pub fn workspace_root() -> PathBuf {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    root.canonicalize().unwrap_or(root)
+}
+
+fn render(template: &str, day: u8) -> String {
+    template.replace("{{day}}", &day.to_string())
+}
+
+/// Inserts `line` after the last line matching `is_anchor`, keeping the anchor's indentation.
+fn insert_after_last(text: &str, is_anchor: impl Fn(&str) -> bool, line: &str) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let idx = lines.iter().rposition(|line| is_anchor(line))?;
+    let indent = &lines[idx][..lines[idx].len() - lines[idx].trim_start().len()];
+
+    let mut result: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    result.insert(idx + 1, format!("{}{}", indent, line));
+    Some(result.join("\n") + "\n")
+}
+
+/// Adds `src/dayN` to the members and dependencies of the workspace manifest.
+fn register_workspace(manifest: &str, day: u8) -> Option<String> {
+    let members = manifest.find("members")?;
+    let members_end = members + manifest[members..].find(']')?;
+
+    // The day crates are listed on one line, so the new one goes at the end of it
+    let last_day = manifest[..members_end].rfind("\"src/day")?;
+    let line_end = last_day + manifest[last_day..].find('\n')?;
+    let manifest = format!(
+        "{} \"src/day{}\",{}",
+        manifest[..line_end].trim_end(),
+        day,
+        &manifest[line_end..]
+    );
+
+    insert_after_last(
+        &manifest,
+        |line| line.starts_with("day") && line.contains("path = \"src/day"),
+        &format!("day{} = {{path = \"src/day{}\"}}", day, day),
+    )
+}
+
+/// Adds the day to the dependencies of the runner and to its registry.
+fn register_runner(manifest: &str, registry: &str, day: u8) -> Option<(String, String)> {
+    let manifest = insert_after_last(
+        manifest,
+        |line| line.starts_with("day") && line.contains("workspace = true"),
+        &format!("day{} = {{ workspace = true }}", day),
+    )?;
+    let registry = insert_after_last(
+        registry,
+        |line| line.trim_start().starts_with("&day"),
+        &format!("&day{}::Day{},", day, day),
+    )?;
+
+    Some((manifest, registry))
+}
+
+/// Creates the crate of a new day from the templates and registers it everywhere a day has to be
+/// known, returning the directory of the new crate.
+pub fn scaffold(root: &Path, day: u8) -> Result<PathBuf, Error> {
+    let dir = root.join("src").join(format!("day{}", day));
+    if dir.exists() {
+        return Err(Error::new(format!("{} already exists", dir.display())));
+    }
+
+    let read = |path: PathBuf| {
+        fs::read_to_string(&path)
+            .map(|text| (path.clone(), text))
+            .map_err(|e| Error::from(e).in_file(&path))
+    };
+    let (workspace_path, workspace) = read(root.join("Cargo.toml"))?;
+    let (runner_path, runner) = read(root.join("src/aoc/Cargo.toml"))?;
+    let (registry_path, registry) = read(root.join("src/aoc/src/registry.rs"))?;
+
+    if workspace.contains(&format!("\"src/day{}\"", day)) {
+        return Err(
+            Error::new(format!("Day {} is already a workspace member", day))
+                .in_file(&workspace_path),
+        );
+    }
+
+    // Everything is prepared before writing, so a failure leaves the workspace untouched
+    let workspace = register_workspace(&workspace, day).ok_or_else(|| {
+        Error::new("Could not find the day crates in the workspace manifest")
+            .in_file(&workspace_path)
+    })?;
+    let (runner, registry) = register_runner(&runner, &registry, day)
+        .ok_or_else(|| Error::new("Could not find where to register the day in the runner"))?;
+
+    let files = [
+        (dir.join("Cargo.toml"), render(CARGO_TEMPLATE, day)),
+        (dir.join("src/lib.rs"), render(LIB_TEMPLATE, day)),
+        (dir.join("src/main.rs"), render(MAIN_TEMPLATE, day)),
+        (dir.join("input_example.txt"), String::new()),
+        (workspace_path, workspace),
+        (runner_path, runner),
+        (registry_path, registry),
+    ];
+
+    fs::create_dir_all(dir.join("src")).map_err(|e| Error::from(e).in_file(&dir))?;
+    for (path, text) in files {
+        fs::write(&path, text).map_err(|e| Error::from(e).in_file(&path))?;
+    }
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::{register_runner, register_workspace};
+
+    #[test]
+    fn test_register_workspace() {
+        let manifest = "[workspace]\nmembers = [\"src/core\", \"src/aoc\",\n    \"src/day1\", \"src/day2\",\n]\n\n[workspace.dependencies]\ncore = {path = \"src/core\"}\nday1 = {path = \"src/day1\"}\nday2 = {path = \"src/day2\"}\n";
+
+        assert_eq!(
+            register_workspace(manifest, 3).unwrap(),
+            "[workspace]\nmembers = [\"src/core\", \"src/aoc\",\n    \"src/day1\", \"src/day2\", \"src/day3\",\n]\n\n[workspace.dependencies]\ncore = {path = \"src/core\"}\nday1 = {path = \"src/day1\"}\nday2 = {path = \"src/day2\"}\nday3 = {path = \"src/day3\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_register_runner() {
+        let manifest = "[dependencies]\ncore = { workspace = true }\nday1 = { workspace = true }\n";
+        let registry = "pub fn solutions() -> Vec<&'static dyn Runner> {\n    vec![\n        &day1::Day1,\n    ]\n}\n";
+
+        let (manifest, registry) = register_runner(manifest, registry, 2).unwrap();
+        assert!(manifest.ends_with("day1 = { workspace = true }\nday2 = { workspace = true }\n"));
+        assert!(registry.contains("        &day1::Day1,\n        &day2::Day2,\n    ]"));
+    }
+}
@@ -0,0 +1,400 @@
+use crate::config::Config;
+use crate::fetch::USER_AGENT;
+use crate::http;
+use core::Error;
+use core::solution::Part;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Submitted too soon after the previous answer, nothing was checked.
+    Wait(Duration),
+    /// The part is already solved, nothing was checked.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Name used in the submission log, `None` for verdicts that say nothing about the answer.
+    fn name(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Correct => Some("correct"),
+            Verdict::TooHigh => Some("too_high"),
+            Verdict::TooLow => Some("too_low"),
+            Verdict::Wrong => Some("wrong"),
+            Verdict::Wait(_) | Verdict::AlreadySolved => None,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Verdict> {
+        match name {
+            "correct" => Some(Verdict::Correct),
+            "too_high" => Some(Verdict::TooHigh),
+            "too_low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::TooHigh => write!(f, "Wrong answer, too high"),
+            Verdict::TooLow => write!(f, "Wrong answer, too low"),
+            Verdict::Wrong => write!(f, "Wrong answer"),
+            Verdict::Wait(duration) => write!(
+                f,
+                "Answer submitted too recently, wait {}s before trying again",
+                duration.as_secs()
+            ),
+            Verdict::AlreadySolved => write!(f, "This part is already solved"),
+        }
+    }
+}
+
+/// Reads the verdict out of the HTML page returned for a submitted answer.
+pub fn parse_response(body: &str) -> Result<Verdict, Error> {
+    if body.contains("That's the right answer") {
+        return Ok(Verdict::Correct);
+    }
+    if body.contains("You don't seem to be solving the right level") {
+        return Ok(Verdict::AlreadySolved);
+    }
+    if body.contains("You gave an answer too recently") {
+        return Ok(Verdict::Wait(
+            parse_wait(body).unwrap_or(Duration::from_secs(60)),
+        ));
+    }
+    if body.contains("That's not the right answer") {
+        return Ok(if body.contains("too high") {
+            Verdict::TooHigh
+        } else if body.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        });
+    }
+
+    Err(Error::new(format!(
+        "Could not understand the response: {}",
+        body.trim()
+    )))
+}
+
+/// Parses `You have 1m 30s left to wait`.
+fn parse_wait(body: &str) -> Option<Duration> {
+    let (_, rest) = body.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        .map(|part| {
+            let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let number = number.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Local log of every answer the server judged, used to avoid submitting the same wrong answer
+/// twice or one that is on the wrong side of a known bound. Stored as tab separated lines
+/// `<day> <part> <verdict> <answer>`.
+#[derive(Debug, Default)]
+pub struct SubmissionLog {
+    pub submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// `submissions.tsv` in the root of the workspace.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../submissions.tsv")
+    }
+
+    pub fn load(path: &Path) -> Result<SubmissionLog, Error> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(SubmissionLog::default()),
+            Err(e) => return Err(Error::from(e).in_file(path)),
+        };
+
+        let submissions = core::parse_lines(&text, |line| {
+            let (day, part, verdict, answer) =
+                core::scan!("{}\t{}\t{}\t{}", line => u8, Part, String, String)?;
+            let verdict = Verdict::from_name(&verdict)
+                .ok_or_else(|| Error::new(format!("Unknown verdict: {}", verdict)))?;
+
+            Ok(Submission {
+                day,
+                part,
+                answer,
+                verdict,
+            })
+        })
+        .map_err(|e| e.in_file(path))?;
+
+        Ok(SubmissionLog { submissions })
+    }
+
+    pub fn append(path: &Path, submission: &Submission) -> Result<(), Error> {
+        let Some(verdict) = submission.verdict.name() else {
+            return Ok(());
+        };
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| Error::from(e).in_file(path))?;
+
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}",
+            submission.day, submission.part, verdict, submission.answer
+        )
+        .map_err(|e| Error::from(e).in_file(path))
+    }
+
+    /// Fails if submitting `answer` is pointless given the earlier verdicts.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Result<(), Error> {
+        let earlier = self
+            .submissions
+            .iter()
+            .filter(|submission| submission.day == day && submission.part == part);
+
+        for submission in earlier {
+            let refuse = |reason: String| {
+                Err(Error::new(format!(
+                    "Not submitting {} for day {} part {}: {}",
+                    answer, day, part, reason
+                )))
+            };
+
+            if submission.verdict == Verdict::Correct {
+                return refuse(format!("already solved with {}", submission.answer));
+            }
+            if submission.answer == answer {
+                return refuse("it was rejected before".to_string());
+            }
+
+            match (
+                submission.verdict,
+                compare_numbers(answer, &submission.answer),
+            ) {
+                (Verdict::TooHigh, Some(Ordering::Greater)) => {
+                    return refuse(format!("{} was already too high", submission.answer));
+                }
+                (Verdict::TooLow, Some(Ordering::Less)) => {
+                    return refuse(format!("{} was already too low", submission.answer));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn compare_numbers(left: &str, right: &str) -> Option<Ordering> {
+    let left = left.trim().parse::<i128>().ok()?;
+    let right = right.trim().parse::<i128>().ok()?;
+    Some(left.cmp(&right))
+}
+
+/// Percent-encodes `value` for an `application/x-www-form-urlencoded` body.
+fn form_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'*' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            byte => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Submits `answer` unless the log says it is known to be wrong, and records the verdict.
+/// Surrounding whitespace is not part of the answer.
+pub fn submit(
+    config: &Config,
+    log_path: &Path,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict, Error> {
+    let answer = answer.trim();
+    SubmissionLog::load(log_path)?.check(day, part, answer)?;
+
+    let cookie = format!("session={}", config.session()?);
+    let url = format!("{}/answer", config.day_url(day));
+    let body = format!("level={}&answer={}", part, form_encode(answer));
+    let response = http::request(
+        "POST",
+        &url,
+        &[
+            ("Cookie", &cookie),
+            ("User-Agent", USER_AGENT),
+            ("Content-Type", "application/x-www-form-urlencoded"),
+        ],
+        Some(&body),
+    )?;
+
+    if !response.is_success() {
+        return Err(Error::new(format!(
+            "Could not submit answer for day {} part {}: HTTP {}: {}",
+            day,
+            part,
+            response.status,
+            response.body.trim()
+        )));
+    }
+
+    let verdict = parse_response(&response.body)?;
+    SubmissionLog::append(
+        log_path,
+        &Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        },
+    )?;
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+    use crate::mock::MockServer;
+    use crate::submit::{Verdict, form_encode, parse_response, submit};
+    use core::solution::Part;
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn temp_file() -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        env::temp_dir().join(format!("aoc-submit-{}-{}.tsv", std::process::id(), nanos))
+    }
+
+    #[test]
+    fn test_parse_responses() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer! You are one gold star closer."
+            ))
+            .unwrap(),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            ))
+            .unwrap(),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently. You have 1m 30s left to wait."
+            ))
+            .unwrap(),
+            Verdict::Wait(Duration::from_secs(90))
+        );
+        assert!(parse_response("<html>Something else</html>").is_err());
+    }
+
+    #[test]
+    fn test_submit_remembers_bounds() {
+        let server = MockServer::start(vec![(
+            200,
+            "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        )]);
+        let config = Config {
+            session: Some("secret".to_string()),
+            base_url: server.url.clone(),
+            ..Config::default()
+        };
+        let log = temp_file();
+
+        assert_eq!(
+            submit(&config, &log, 8, Part::Two, "6844300").unwrap(),
+            Verdict::TooHigh
+        );
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2025/day/8/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=6844300"));
+
+        let again = submit(&config, &log, 8, Part::Two, "6844300").unwrap_err();
+        assert!(again.message().contains("rejected before"));
+        let higher = submit(&config, &log, 8, Part::Two, "7000000").unwrap_err();
+        assert!(higher.message().contains("already too high"));
+        assert_eq!(server.requests().len(), 1);
+
+        fs::remove_file(&log).unwrap();
+    }
+
+    #[test]
+    fn test_form_encode() {
+        assert_eq!(form_encode("6844300"), "6844300");
+        assert_eq!(form_encode("a&b=c+d e"), "a%26b%3Dc%2Bd+e");
+    }
+
+    #[test]
+    fn test_submit_trims_answer() {
+        let server = MockServer::start(vec![(
+            200,
+            "<article><p>That's not the right answer.</p></article>",
+        )]);
+        let config = Config {
+            session: Some("secret".to_string()),
+            base_url: server.url.clone(),
+            ..Config::default()
+        };
+        let log = temp_file();
+
+        assert_eq!(
+            submit(&config, &log, 3, Part::One, " 17301\n").unwrap(),
+            Verdict::Wrong
+        );
+        assert!(server.requests()[0].ends_with("level=1&answer=17301"));
+        assert!(fs::read_to_string(&log).unwrap().ends_with("\t17301\n"));
+        let again = submit(&config, &log, 3, Part::One, "17301").unwrap_err();
+        assert!(again.message().contains("rejected before"));
+
+        fs::remove_file(&log).unwrap();
+    }
+}
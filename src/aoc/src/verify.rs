@@ -0,0 +1,154 @@
+use crate::answers::Answers;
+use core::Error;
+use core::error;
+use core::example::Example;
+use core::input::{InputKind, InputLocator};
+use core::solution::{Params, Part, Runner};
+use std::fmt::{Display, Formatter};
+use std::panic;
+use std::panic::AssertUnwindSafe;
+
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// No answer recorded, so nothing was run.
+    Missing,
+    Error(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL    got {}, expected {}", actual, expected)
+            }
+            Status::Missing => write!(f, "missing"),
+            Status::Error(message) => write!(f, "ERROR   {}", message),
+        }
+    }
+}
+
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub kind: InputKind,
+    pub status: Status,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {} Part {} {:<7} {}",
+            self.day, self.part, self.kind, self.status
+        )
+    }
+}
+
+/// Runs `part` of `solution` on `input`, turning a panic into an error so one broken day does
+/// not stop the others from being checked.
+pub fn solve_catching(
+    solution: &dyn Runner,
+    input: &str,
+    part: Part,
+    params: &Params,
+) -> Result<String, Error> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        solution.solve_with(input, part, params)
+    }))
+    .unwrap_or_else(|payload| {
+        Err(Error::new(format!(
+            "panicked: {}",
+            error::panic_message(&*payload)
+        )))
+    })
+}
+
+/// Annotation of the default example of `day`, if it has one.
+pub fn annotation(day: u8) -> Result<Option<Example>, Error> {
+    match InputLocator::new(day).locate(InputKind::Example) {
+        Ok(path) => Example::load(&path),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Checks both parts of `solution` on the real and the example input. Expected answers come
+/// from `answers` and, for the example, from its annotation, which also provides parameters.
+pub fn verify(solution: &dyn Runner, answers: &Answers) -> Vec<Check> {
+    let day = solution.day();
+    let mut checks: Vec<Check> = vec![];
+
+    for kind in [InputKind::Real, InputKind::Example] {
+        let example = match kind {
+            InputKind::Real => Ok(None),
+            InputKind::Example => annotation(day),
+        };
+        let example = match example {
+            Ok(example) => example,
+            Err(error) => {
+                checks.extend(Part::ALL.iter().map(|&part| Check {
+                    day,
+                    part,
+                    kind,
+                    status: Status::Error(error.to_string()),
+                }));
+                continue;
+            }
+        };
+
+        let recorded: Vec<(Part, Option<&str>, Params)> = Part::ALL
+            .iter()
+            .map(|&part| match example {
+                Some(ref example) => (
+                    part,
+                    example
+                        .expected(part)
+                        .or_else(|| answers.get(day, part, kind)),
+                    example.params(part),
+                ),
+                None => (part, answers.get(day, part, kind), Params::new()),
+            })
+            .collect();
+
+        let input = match recorded.iter().any(|(_, expected, _)| expected.is_some()) {
+            true => Some(InputLocator::new(day).read(kind)),
+            false => None,
+        };
+
+        for (part, expected, params) in recorded {
+            let status = match (expected, &input) {
+                (None, _) | (_, None) => Status::Missing,
+                (Some(_), Some(Err(error))) => Status::Error(error.message().to_string()),
+                (Some(expected), Some(Ok(input))) => {
+                    match input.parse(|text| solve_catching(solution, text, part, &params)) {
+                        Ok(actual) if actual == expected => Status::Pass,
+                        Ok(actual) => Status::Fail {
+                            expected: expected.to_string(),
+                            actual,
+                        },
+                        Err(error) => Status::Error(error.message().to_string()),
+                    }
+                }
+            };
+
+            checks.push(Check {
+                day,
+                part,
+                kind,
+                status,
+            });
+        }
+    }
+
+    checks
+}
@@ -0,0 +1,277 @@
+use core::Error;
+use core::input::{InputKind, InputLocator};
+use core::output::Record;
+use core::solution::Part;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Modification time and size of every watched file, `None` for files that do not exist.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    files: BTreeMap<PathBuf, Option<(SystemTime, u64)>>,
+}
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Snapshot {
+        let files = paths
+            .iter()
+            .map(|path| {
+                let stamp = fs::metadata(path)
+                    .ok()
+                    .and_then(|metadata| Some((metadata.modified().ok()?, metadata.len())));
+                (path.clone(), stamp)
+            })
+            .collect();
+
+        Snapshot { files }
+    }
+
+    /// Files that were added, removed or modified since `earlier`.
+    pub fn changed_since(&self, earlier: &Snapshot) -> Vec<PathBuf> {
+        self.files
+            .iter()
+            .filter(|(path, stamp)| earlier.files.get(*path) != Some(stamp))
+            .map(|(path, _)| path)
+            .chain(
+                earlier
+                    .files
+                    .keys()
+                    .filter(|path| !self.files.contains_key(*path)),
+            )
+            .cloned()
+            .collect()
+    }
+}
+
+/// Sources, manifest, inputs and example annotations of `day`. Build output is skipped.
+pub fn watched_files(root: &Path, day: u8) -> Vec<PathBuf> {
+    fn walk(dir: &Path, files: &mut Vec<PathBuf>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+            if path.is_dir() && !path.ends_with("target") {
+                walk(&path, files);
+            } else if path.is_file() {
+                files.push(path);
+            }
+        }
+    }
+
+    let dir = root.join("src").join(format!("day{}", day));
+    let mut files = vec![];
+    walk(&dir.canonicalize().unwrap_or(dir), &mut files);
+
+    // The inputs may live outside of the crate, e.g. in AOC_INPUT_DIR
+    let locator = InputLocator::new(day);
+    for kind in [InputKind::Real, InputKind::Example] {
+        if let Ok(path) = locator.locate(kind) {
+            files.extend(
+                path.canonicalize()
+                    .ok()
+                    .filter(|path| !files.contains(path)),
+            );
+        }
+    }
+
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// One line per part comparing `current` with the answers of the previous run.
+pub fn diff(previous: &BTreeMap<Part, String>, current: &BTreeMap<Part, String>) -> Vec<String> {
+    Part::ALL
+        .iter()
+        .filter_map(|part| {
+            let line = match (previous.get(part), current.get(part)) {
+                (_, None) => return None,
+                (None, Some(answer)) => format!("Part {}: {}", part, answer),
+                (Some(before), Some(answer)) if before == answer => {
+                    format!("Part {}: {} (unchanged)", part, answer)
+                }
+                (Some(before), Some(answer)) => {
+                    format!("Part {}: {} (was {})", part, answer, before)
+                }
+            };
+            Some(line)
+        })
+        .collect()
+}
+
+/// Builds and runs the day binary with `--format json`. Compiler output goes straight to the
+/// terminal, failed parts are printed and left out of the answers.
+fn solve(root: &Path, day: u8) -> Result<BTreeMap<Part, String>, Error> {
+    let output = Command::new("cargo")
+        .args(["run", "--quiet", "--release", "--package"])
+        .arg(format!("day{}", day))
+        .args(["--", "--format", "json"])
+        .current_dir(root)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| Error::new(format!("Could not run cargo: {}", e)))?;
+
+    let mut answers = BTreeMap::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let Ok(record) = line.parse::<Record>() else {
+            continue;
+        };
+        match record.answer {
+            Ok(answer) => {
+                answers.insert(record.part, answer);
+            }
+            Err(error) => println!("Part {}: ERROR {}", record.part, error),
+        }
+    }
+
+    if answers.is_empty() && !output.status.success() {
+        return Err(Error::new(format!(
+            "cargo run failed with {}",
+            output.status
+        )));
+    }
+
+    Ok(answers)
+}
+
+/// Runs the tests of the day crate and the generated tests for its annotated examples, printing
+/// their output only if something failed.
+fn test(root: &Path, day: u8) -> bool {
+    let package = format!("day{}", day);
+    let filter = format!("day{}_", day);
+    let commands: [&[&str]; 2] = [
+        &["test", "--quiet", "--package", &package],
+        &[
+            "test",
+            "--quiet",
+            "--package",
+            "aoc",
+            "--test",
+            "examples",
+            &filter,
+        ],
+    ];
+
+    commands.iter().all(
+        |&args| match Command::new("cargo").args(args).current_dir(root).output() {
+            Ok(output) if output.status.success() => true,
+            Ok(output) => {
+                print!("{}", String::from_utf8_lossy(&output.stdout));
+                eprint!("{}", String::from_utf8_lossy(&output.stderr));
+                false
+            }
+            Err(error) => {
+                eprintln!("Could not run cargo: {}", error);
+                false
+            }
+        },
+    )
+}
+
+/// Re-runs the solver and the example tests of `day` whenever one of its files changes. Never
+/// returns.
+pub fn watch(root: &Path, day: u8, interval: Duration) -> ! {
+    let mut previous: BTreeMap<Part, String> = BTreeMap::new();
+    let mut snapshot = Snapshot::default();
+
+    loop {
+        let current = Snapshot::take(&watched_files(root, day));
+        let changed = current.changed_since(&snapshot);
+
+        if !changed.is_empty() {
+            if !snapshot.files.is_empty() {
+                let names: Vec<String> = changed
+                    .iter()
+                    .map(|path| {
+                        path.strip_prefix(root)
+                            .unwrap_or(path)
+                            .display()
+                            .to_string()
+                    })
+                    .collect();
+                println!("\nChanged: {}", names.join(", "));
+            }
+            snapshot = current;
+
+            match solve(root, day) {
+                Ok(answers) => {
+                    for line in diff(&previous, &answers) {
+                        println!("{}", line);
+                    }
+                    previous.extend(answers);
+                }
+                Err(error) => eprintln!("{}", error),
+            }
+
+            match test(root, day) {
+                true => println!("Example tests passed"),
+                false => println!("Example tests FAILED"),
+            }
+            println!("Watching day {} for changes...", day);
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::watch::{Snapshot, diff, watched_files};
+    use core::solution::Part;
+    use std::collections::BTreeMap;
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn test_snapshot_changes() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (kept, edited, created) = (dir.join("a.rs"), dir.join("b.rs"), dir.join("c.txt"));
+        fs::write(&kept, "a").unwrap();
+        fs::write(&edited, "b").unwrap();
+        let paths = [kept.clone(), edited.clone(), created.clone()];
+
+        let before = Snapshot::take(&paths);
+        fs::write(&edited, "bb").unwrap();
+        fs::write(&created, "c").unwrap();
+        let after = Snapshot::take(&paths);
+
+        assert_eq!(
+            after.changed_since(&before),
+            vec![edited.clone(), created.clone()]
+        );
+        assert!(after.changed_since(&after).is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_watched_files() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let files = watched_files(&root, 8);
+        assert!(files.iter().any(|path| path.ends_with("day8/src/lib.rs")));
+        assert!(
+            files
+                .iter()
+                .any(|path| path.ends_with("day8/input_example.answers"))
+        );
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let previous =
+            BTreeMap::from([(Part::One, "40".to_string()), (Part::Two, "1".to_string())]);
+        let current = BTreeMap::from([(Part::One, "40".to_string()), (Part::Two, "2".to_string())]);
+
+        assert_eq!(
+            diff(&previous, &current),
+            vec!["Part 1: 40 (unchanged)", "Part 2: 2 (was 1)"]
+        );
+        assert_eq!(diff(&BTreeMap::new(), &current)[1], "Part 2: 2");
+    }
+}
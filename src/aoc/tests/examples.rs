@@ -0,0 +1,23 @@
+use aoc::registry;
+use core::example::Example;
+use core::reader;
+use core::solution::Part;
+use std::fs::File;
+use std::path::Path;
+
+/// Solves `part` of the example at `input` with its parameters and compares it with the
+/// annotated answer.
+fn check(day: u8, input: &str, part: Part) {
+    let path = Path::new(input);
+    let example = Example::load(path).unwrap().unwrap();
+    let solution = registry::find(day).expect("day is not registered in the runner");
+
+    let text = reader::read_to_string(File::open(path).unwrap()).unwrap();
+    let answer = solution
+        .solve_with(&text, part, &example.params(part))
+        .unwrap();
+
+    assert_eq!(Some(answer.as_str()), example.expected(part));
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
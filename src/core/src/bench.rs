@@ -0,0 +1,216 @@
+use crate::error::Error;
+use crate::solution::{Params, Solution};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator that counts allocations, so the harness can report them. A binary opts in
+/// with
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: core::bench::CountingAllocator = core::bench::CountingAllocator;
+/// ```
+///
+/// Without it all allocation counts are reported as zero.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// Number of allocations made so far, if [`CountingAllocator`] is installed.
+pub fn allocations() -> u64 {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Untimed runs before measuring, to warm up caches and the allocator.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 5,
+            iterations: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    /// Allocations per iteration.
+    pub allocations: u64,
+}
+
+impl Stats {
+    /// Computes the statistics from unsorted samples, which must not be empty.
+    pub fn from_samples(mut samples: Vec<Duration>, allocations: u64) -> Self {
+        samples.sort();
+        let p95 = (samples.len() * 95).div_ceil(100).max(1) - 1;
+
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            p95: samples[p95],
+            allocations: allocations / samples.len() as u64,
+        }
+    }
+}
+
+/// Runs `f` `config.warmup` times untimed and then `config.iterations` times timed.
+pub fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let iterations = config.iterations.max(1);
+    let mut samples: Vec<Duration> = Vec::with_capacity(iterations);
+    let allocations_before = allocations();
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let result = f();
+        samples.push(start.elapsed());
+        // Dropping is not part of the measurement
+        drop(black_box(result));
+    }
+
+    Stats::from_samples(samples, allocations() - allocations_before)
+}
+
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub name: &'static str,
+    pub stats: Stats,
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub day: u8,
+    pub config: BenchConfig,
+    pub measurements: Vec<Measurement>,
+}
+
+/// Times parsing and both parts of `S` separately. The parts run on an input parsed once up front.
+pub fn bench<S: Solution>(input: &str, config: &BenchConfig) -> Result<BenchReport, Error> {
+    bench_with::<S>(input, config, &[Params::new(), Params::new()])
+}
+
+/// Like [`bench`], solving each part with its [`Params`], e.g. those of an annotated example.
+/// Both parts are solved once before timing, so invalid parameters fail instead of being timed.
+pub fn bench_with<S: Solution>(
+    input: &str,
+    config: &BenchConfig,
+    params: &[Params; 2],
+) -> Result<BenchReport, Error> {
+    let parsed = S::parse(input)?;
+    S::part1_with(&parsed, &params[0])?;
+    S::part2_with(&parsed, &params[1])?;
+
+    let measurements = vec![
+        Measurement {
+            name: "parse",
+            stats: measure(config, || S::parse(black_box(input))),
+        },
+        Measurement {
+            name: "part1",
+            stats: measure(config, || {
+                S::part1_with(black_box(&parsed), &params[0]).map(|answer| answer.to_string())
+            }),
+        },
+        Measurement {
+            name: "part2",
+            stats: measure(config, || {
+                S::part2_with(black_box(&parsed), &params[1]).map(|answer| answer.to_string())
+            }),
+        },
+    ];
+
+    Ok(BenchReport {
+        day: S::DAY,
+        config: *config,
+        measurements,
+    })
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Day {} ({} iterations, {} warmup)",
+            self.day, self.config.iterations, self.config.warmup
+        )?;
+        write!(
+            f,
+            "{:<6} {:>12} {:>12} {:>12} {:>10}",
+            "", "min", "median", "p95", "allocs"
+        )?;
+
+        for measurement in &self.measurements {
+            let stats = &measurement.stats;
+            write!(
+                f,
+                "\n{:<6} {:>12.2?} {:>12.2?} {:>12.2?} {:>10}",
+                measurement.name, stats.min, stats.median, stats.p95, stats.allocations
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{BenchConfig, Stats, measure};
+    use std::time::Duration;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples, 40);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(11));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        assert_eq!(stats.allocations, 2);
+    }
+
+    #[test]
+    fn test_measure_runs_warmup_and_iterations() {
+        let mut calls = 0;
+        let config = BenchConfig {
+            warmup: 2,
+            iterations: 3,
+        };
+        measure(&config, || calls += 1);
+        assert_eq!(calls, 5);
+    }
+}
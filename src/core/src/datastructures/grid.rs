@@ -0,0 +1,62 @@
+/// Rectangular grid stored row by row, addressed as `(x, y)` with `y` growing downwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    rows: Vec<Vec<T>>,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    /// Returns `None` if the rows differ in length.
+    pub fn new(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self { rows, width })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.rows.get(y)?.get(x)
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.rows.get_mut(y)?.get_mut(x)
+    }
+
+    pub fn rows(&self) -> &[Vec<T>] {
+        &self.rows
+    }
+
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        self.rows
+    }
+
+    /// Iterates all cells in reading order together with their `(x, y)` coordinates.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| ((x, y), cell)))
+    }
+
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Grid<U> {
+        Grid {
+            rows: self
+                .rows
+                .into_iter()
+                .map(|row| row.into_iter().map(&mut f).collect())
+                .collect(),
+            width: self.width,
+        }
+    }
+}
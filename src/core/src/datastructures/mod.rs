@@ -1 +1,2 @@
-pub mod interval_tree;
\ No newline at end of file
+pub mod grid;
+pub mod interval_tree;
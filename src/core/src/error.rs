@@ -0,0 +1,273 @@
+use std::any::Any;
+use std::char::ParseCharError;
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::num::{ParseFloatError, ParseIntError};
+use std::path::{Path, PathBuf};
+use std::str::ParseBoolError;
+
+/// Error raised while reading or parsing puzzle input.
+///
+/// Parsers only know the text they are handed, so the location is filled in step by step:
+/// a field parser sets the column, the line based caller adds the line number and the source
+/// line, and whoever opened the file finally adds its path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    message: String,
+    file: Option<PathBuf>,
+    line: Option<usize>,
+    column: Option<usize>,
+    length: usize,
+    snippet: Option<String>,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            file: None,
+            line: None,
+            column: None,
+            length: 1,
+            snippet: None,
+        }
+    }
+
+    /// Error about the `length` characters starting at the 1-based `column`. Column 0 is treated
+    /// as 1.
+    pub fn at(message: impl Into<String>, column: usize, length: usize) -> Self {
+        Self {
+            column: Some(column.max(1)),
+            length: length.max(1),
+            ..Self::new(message)
+        }
+    }
+
+    /// Error about `part`, which has to be a subslice of `source`.
+    pub fn at_slice(message: impl Into<String>, source: &str, part: &str) -> Self {
+        let offset = part.as_ptr() as usize - source.as_ptr() as usize;
+        let column = source[..offset].chars().count() + 1;
+        Self::at(message, column, part.chars().count())
+    }
+
+    /// Moves the column right by `offset` characters, for errors from parsers that only saw a
+    /// part of the line.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.column = Some(self.column.unwrap_or(1) + offset);
+        self
+    }
+
+    /// Points the error at the `length` characters starting at `column`, unless a column is already
+    /// known.
+    pub fn or_at(mut self, column: usize, length: usize) -> Self {
+        if self.column.is_none() {
+            self.column = Some(column.max(1));
+            self.length = length.max(1);
+        }
+        self
+    }
+
+    /// Moves the line down by `offset` lines, for errors from parsers that only saw a part of the
+    /// input.
+    pub fn with_line_offset(mut self, offset: usize) -> Self {
+        if let Some(line) = self.line {
+            self.line = Some(line + offset);
+        }
+        self
+    }
+
+    /// Attaches the 1-based line number and the full source line, unless already set.
+    pub fn with_line(mut self, line: usize, snippet: &str) -> Self {
+        if self.line.is_none() {
+            self.line = Some(line);
+            self.snippet = Some(snippet.to_string());
+        }
+        self
+    }
+
+    /// Prefixes the message, e.g. with the part of the input that was being parsed.
+    pub fn context(mut self, context: impl Display) -> Self {
+        self.message = format!("{}: {}", context, self.message);
+        self
+    }
+
+    pub fn in_file(mut self, file: &Path) -> Self {
+        if self.file.is_none() {
+            self.file = Some(file.to_path_buf());
+        }
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "error: {}", self.message)?;
+
+        if self.file.is_none() && self.line.is_none() {
+            return Ok(());
+        }
+
+        let file = self
+            .file
+            .as_ref()
+            .map_or("<input>".to_string(), |file| file.display().to_string());
+        write!(f, "\n --> {}", file)?;
+
+        let Some(line) = self.line else {
+            return Ok(());
+        };
+        write!(f, ":{}", line)?;
+        if let Some(column) = self.column {
+            write!(f, ":{}", column)?;
+        }
+
+        if let Some(ref snippet) = self.snippet {
+            let gutter = " ".repeat(line.to_string().len());
+            write!(f, "\n{} |\n{} | {}", gutter, line, snippet)?;
+            if let Some(column) = self.column {
+                write!(
+                    f,
+                    "\n{} | {}{}",
+                    gutter,
+                    " ".repeat(column.saturating_sub(1)),
+                    "^".repeat(self.length)
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Message of a panic caught with [`std::panic::catch_unwind`], empty if the payload is neither
+/// a `&str` nor a `String`.
+pub fn panic_message(payload: &dyn Any) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::new(error.to_string())
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(error: ParseIntError) -> Self {
+        Error::new(error.to_string())
+    }
+}
+
+impl From<ParseFloatError> for Error {
+    fn from(error: ParseFloatError) -> Self {
+        Error::new(error.to_string())
+    }
+}
+
+impl From<ParseCharError> for Error {
+    fn from(error: ParseCharError) -> Self {
+        Error::new(error.to_string())
+    }
+}
+
+impl From<ParseBoolError> for Error {
+    fn from(error: ParseBoolError) -> Self {
+        Error::new(error.to_string())
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(error: Infallible) -> Self {
+        match error {}
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::new(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::new(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{Error, panic_message};
+    use std::panic;
+    use std::path::Path;
+
+    #[test]
+    fn test_display_plain() {
+        assert_eq!(Error::new("broken").to_string(), "error: broken");
+    }
+
+    #[test]
+    fn test_display_with_caret() {
+        let line = "162,8x7,812";
+        let error = Error::at_slice("Invalid y: 8x7", line, &line[4..7])
+            .with_line(12, line)
+            .in_file(Path::new("src/day8/input.txt"));
+
+        assert_eq!(
+            error.to_string(),
+            "error: Invalid y: 8x7\n --> src/day8/input.txt:12:5\n   |\n12 | 162,8x7,812\n   |     ^^^"
+        );
+    }
+
+    #[test]
+    fn test_offset_and_first_line_wins() {
+        let error = Error::at("bad", 2, 1)
+            .with_offset(3)
+            .with_line(1, "first")
+            .with_line(2, "second");
+
+        assert_eq!(error.column(), Some(5));
+        assert_eq!(error.line(), Some(1));
+    }
+
+    #[test]
+    fn test_column_zero() {
+        let error = Error::at("bad", 0, 2).with_line(3, "ab");
+        assert_eq!(error.column(), Some(1));
+        assert_eq!(
+            error.to_string(),
+            "error: bad\n --> <input>:3:1\n  |\n3 | ab\n  | ^^"
+        );
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let payload = panic::catch_unwind(|| panic!("doomed at {}", 7)).unwrap_err();
+        assert_eq!(panic_message(&*payload), "doomed at 7");
+        assert_eq!(panic_message(&"static"), "static");
+        assert_eq!(panic_message(&7), "");
+    }
+}
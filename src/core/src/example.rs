@@ -0,0 +1,176 @@
+use crate::error::Error;
+use crate::input::{InputKind, InputLocator};
+use crate::solution::{Params, Part};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Extension of the file next to an example input that holds its expected answers.
+pub const ANSWERS_EXTENSION: &str = "answers";
+
+/// An example input together with the answers the puzzle description gives for it.
+///
+/// The answers live in a sidecar file with the same name and the extension `.answers`, e.g.
+/// `input_example.answers` for `input_example.txt`, holding `key = value` lines:
+///
+/// ```text
+/// # The example only makes 10 connections
+/// part1 = 40
+/// part2 = 25272
+/// connections = 10
+/// ```
+///
+/// `part1` and `part2` are the expected answers, either may be left out. Every other key is a
+/// parameter for both parts, unless prefixed with `part1.` or `part2.` to apply to one part only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: PathBuf,
+    expected: [Option<String>; 2],
+    params: Params,
+    part_params: [Params; 2],
+}
+
+impl Example {
+    pub fn sidecar(input: &Path) -> PathBuf {
+        input.with_extension(ANSWERS_EXTENSION)
+    }
+
+    /// Reads the annotation of `input`, `None` if it has no sidecar file.
+    pub fn load(input: &Path) -> Result<Option<Example>, Error> {
+        let sidecar = Example::sidecar(input);
+        if !sidecar.is_file() {
+            return Ok(None);
+        }
+
+        let text = fs::read_to_string(&sidecar).map_err(|e| Error::from(e).in_file(&sidecar))?;
+        let example = Example::parse(input, &text).map_err(|e| e.in_file(&sidecar))?;
+        Ok(Some(example))
+    }
+
+    pub fn parse(input: &Path, text: &str) -> Result<Example, Error> {
+        let mut example = Example {
+            input: input.to_path_buf(),
+            expected: [None, None],
+            params: Params::new(),
+            part_params: [Params::new(), Params::new()],
+        };
+
+        for (idx, line) in text.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = trimmed
+                .split_once('=')
+                .map(|(key, value)| (key.trim(), value.trim()))
+                .ok_or_else(|| Error::new("Expected key = value").with_line(idx + 1, line))?;
+
+            match key.split_once('.') {
+                None if key == "part1" => example.expected[0] = Some(value.to_string()),
+                None if key == "part2" => example.expected[1] = Some(value.to_string()),
+                None => example.params.set(key, value),
+                Some(("part1", name)) => example.part_params[0].set(name, value),
+                Some(("part2", name)) => example.part_params[1].set(name, value),
+                Some(_) => {
+                    return Err(Error::at_slice(
+                        format!("Unknown part in {}, expected part1. or part2.", key),
+                        line,
+                        key,
+                    )
+                    .with_line(idx + 1, line));
+                }
+            }
+        }
+
+        Ok(example)
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        self.expected[part.number() as usize - 1].as_deref()
+    }
+
+    /// Parameters for solving `part` of this example.
+    pub fn params(&self, part: Part) -> Params {
+        let mut params = self.params.clone();
+        params.extend(&self.part_params[part.number() as usize - 1]);
+        params
+    }
+
+    /// Parameters for solving `part` of `input`, empty if it has no sidecar file.
+    pub fn params_for(input: &Path, part: Part) -> Result<Params, Error> {
+        Ok(Example::load(input)?.map_or_else(Params::new, |example| example.params(part)))
+    }
+}
+
+/// All annotated examples of `day`, found next to its default example input, sorted by path.
+pub fn examples(day: u8) -> Result<Vec<Example>, Error> {
+    let Ok(default) = InputLocator::new(day).locate(InputKind::Example) else {
+        return Ok(vec![]);
+    };
+    examples_in(default.parent().unwrap_or(Path::new(".")))
+}
+
+/// All annotated examples in `dir`, sorted by path. Also used by the build script of `aoc` to
+/// generate a test per expected answer.
+pub fn examples_in(dir: &Path) -> Result<Vec<Example>, Error> {
+    let mut sidecars: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| Error::from(e).in_file(dir))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == ANSWERS_EXTENSION))
+        .collect();
+    sidecars.sort();
+
+    let mut examples: Vec<Example> = vec![];
+    for sidecar in sidecars {
+        let input = sidecar.with_extension("txt");
+        if !input.is_file() {
+            return Err(Error::new(format!(
+                "Found answers for {}, which does not exist",
+                input.display()
+            )));
+        }
+        examples.extend(Example::load(&input)?);
+    }
+
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::example::{Example, examples};
+    use crate::solution::{Params, Part};
+    use std::path::Path;
+
+    #[test]
+    fn test_parse_example() {
+        let example = Example::parse(
+            Path::new("input_example.txt"),
+            "# comment\npart1 = 40\nconnections = 10\npart2.connections = 20\n",
+        )
+        .unwrap();
+
+        assert_eq!(example.expected(Part::One), Some("40"));
+        assert_eq!(example.expected(Part::Two), None);
+        assert_eq!(
+            example.params(Part::One),
+            Params::new().with("connections", "10")
+        );
+        assert_eq!(
+            example.params(Part::Two),
+            Params::new().with("connections", "20")
+        );
+    }
+
+    #[test]
+    fn test_parse_example_unknown_part() {
+        let error = Example::parse(Path::new("a.txt"), "part3.x = 1\n").unwrap_err();
+        assert_eq!(error.line(), Some(1));
+    }
+
+    #[test]
+    fn test_examples_of_day() {
+        let found = examples(5).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].expected(Part::Two), Some("14"));
+    }
+}
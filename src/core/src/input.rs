@@ -0,0 +1,362 @@
+use crate::error::Error;
+use crate::example::Example;
+use crate::log;
+use crate::log::Filter;
+use crate::output::Format;
+use crate::reader;
+use crate::solution::{Params, Part};
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Environment variable pointing at a directory laid out like `src/`, i.e. containing `dayN/input.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Explicit input path that reads from stdin instead of a file.
+pub const STDIN: &str = "-";
+
+#[derive(Debug, Default, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum InputKind {
+    #[default]
+    Real,
+    Example,
+}
+
+impl InputKind {
+    fn file_names(&self) -> &'static [&'static str] {
+        match self {
+            InputKind::Real => &["input.txt"],
+            InputKind::Example => &["input_example.txt", "input-example.txt"],
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputKind::Real => "real",
+            InputKind::Example => "example",
+        }
+    }
+}
+
+impl FromStr for InputKind {
+    type Err = String;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "real" => Ok(InputKind::Real),
+            "example" => Ok(InputKind::Example),
+            _ => Err(format!(
+                "Invalid input kind: {}, expected real or example",
+                str
+            )),
+        }
+    }
+}
+
+impl Display for InputKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// Where an input came from: one of the located files of a day, stdin or an explicit path.
+#[derive(Debug, Default, Hash, PartialEq, Eq, Clone)]
+pub enum InputSource {
+    #[default]
+    Real,
+    Example,
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Source selected by `--input <path>` and `--example`, the path wins.
+    pub fn of(kind: InputKind, path: Option<&Path>) -> Self {
+        match path {
+            Some(path) if path == Path::new(STDIN) => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_path_buf()),
+            None => match kind {
+                InputKind::Real => InputSource::Real,
+                InputKind::Example => InputSource::Example,
+            },
+        }
+    }
+
+    /// `real`, `example`, `stdin` or `file`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputSource::Real => "real",
+            InputSource::Example => "example",
+            InputSource::Stdin => "stdin",
+            InputSource::File(_) => "file",
+        }
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            InputSource::File(path) => Some(path),
+            _ => None,
+        }
+    }
+}
+
+/// Text of an input file together with where it came from, so parse errors can name the file.
+pub struct Input {
+    pub path: PathBuf,
+    pub text: String,
+}
+
+impl Input {
+    pub fn parse<T>(&self, parser: impl FnOnce(&str) -> Result<T, Error>) -> Result<T, Error> {
+        parser(&self.text).map_err(|e| e.in_file(&self.path))
+    }
+
+    /// Parameters for solving `part` of this input, from its `.answers` sidecar if it has one.
+    /// Examples need them when the puzzle asks for something smaller, like fewer connections.
+    pub fn params(&self, part: Part) -> Result<Params, Error> {
+        Example::params_for(&self.path, part)
+    }
+}
+
+/// Finds the input file of a day independent of the current working directory.
+///
+/// An explicit path always wins. Otherwise the directory from [`INPUT_DIR_VAR`], the crate
+/// cargo is currently running, the workspace this crate was built in and finally the working
+/// directory are searched, in that order.
+pub struct InputLocator {
+    day: u8,
+    explicit: Option<PathBuf>,
+}
+
+impl InputLocator {
+    pub fn new(day: u8) -> Self {
+        Self {
+            day,
+            explicit: None,
+        }
+    }
+
+    pub fn with_path(mut self, path: Option<PathBuf>) -> Self {
+        self.explicit = path;
+        self
+    }
+
+    pub fn candidates(&self, kind: InputKind) -> Vec<PathBuf> {
+        if let Some(ref path) = self.explicit {
+            return vec![path.clone()];
+        }
+
+        let day_dir = format!("day{}", self.day);
+        let mut roots: Vec<PathBuf> = vec![];
+
+        if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+            roots.push(PathBuf::from(dir));
+        }
+
+        // Set by `cargo run` and `cargo test` to the package being run, which is a sibling of the day crates
+        if let Some(dir) = env::var_os("CARGO_MANIFEST_DIR") {
+            roots.push(Path::new(&dir).join(".."));
+        }
+
+        roots.push(Path::new(env!("CARGO_MANIFEST_DIR")).join(".."));
+        roots.push(PathBuf::from("src"));
+
+        let mut candidates: Vec<PathBuf> = vec![];
+        for root in roots {
+            for file_name in kind.file_names() {
+                candidates.push(root.join(&day_dir).join(file_name));
+            }
+        }
+
+        for file_name in kind.file_names() {
+            candidates.push(PathBuf::from(file_name));
+        }
+
+        candidates
+    }
+
+    pub fn locate(&self, kind: InputKind) -> Result<PathBuf, Error> {
+        let candidates = self.candidates(kind);
+
+        if let Some(path) = candidates.iter().find(|path| path.is_file()) {
+            return Ok(path.clone());
+        }
+
+        let tried: Vec<String> = candidates
+            .iter()
+            .map(|path| format!("  {}", path.display()))
+            .collect();
+
+        Err(Error::new(format!(
+            "Could not find input for day {}, tried:\n{}",
+            self.day,
+            tried.join("\n")
+        )))
+    }
+
+    /// Reads the located file with normalized line endings, or stdin if the explicit path is `-`.
+    pub fn read(&self, kind: InputKind) -> Result<Input, Error> {
+        if self.explicit.as_deref() == Some(Path::new(STDIN)) {
+            return Ok(Input {
+                path: PathBuf::from("<stdin>"),
+                text: reader::read_stdin()?,
+            });
+        }
+
+        let path = self.locate(kind)?;
+        let text = File::open(&path)
+            .map_err(Error::from)
+            .and_then(reader::read_to_string)
+            .map_err(|e| e.in_file(&path))?;
+        Ok(Input { path, text })
+    }
+}
+
+/// Command line of a day binary: `--input <path>`, `--example`, `--format <text|json>` and
+/// `--log <filter>`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Args {
+    pub path: Option<PathBuf>,
+    pub kind: InputKind,
+    pub format: Format,
+    pub log: Option<Filter>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, Error> {
+        let mut args = args.into_iter();
+        let mut parsed = Args::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    let value = args.next().ok_or(Error::new("Missing value for --input"))?;
+                    parsed.path = Some(PathBuf::from(value));
+                }
+                "--example" | "-e" => parsed.kind = InputKind::Example,
+                "--format" | "-f" => {
+                    let value = args
+                        .next()
+                        .ok_or(Error::new("Missing value for --format"))?;
+                    parsed.format = value.parse()?;
+                }
+                "--log" | "-l" => {
+                    let value = args.next().ok_or(Error::new("Missing value for --log"))?;
+                    parsed.log = Some(value.parse()?);
+                }
+                _ => return Err(Error::new(format!("Unknown argument: {}", arg))),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Parses the command line of the running binary and sets up logging from `--log`.
+    pub fn from_env() -> Result<Args, Error> {
+        let args = Args::parse(env::args().skip(1))?;
+        if let Some(ref filter) = args.log {
+            log::init(filter.clone());
+        }
+        Ok(args)
+    }
+
+    pub fn source(&self) -> InputSource {
+        InputSource::of(self.kind, self.path.as_deref())
+    }
+
+    /// Reads the input the arguments point at.
+    pub fn read(&self, day: u8) -> Result<Input, Error> {
+        InputLocator::new(day)
+            .with_path(self.path.clone())
+            .read(self.kind)
+    }
+
+    /// Reads and parses the input the arguments point at.
+    pub fn load<T>(
+        &self,
+        day: u8,
+        parser: impl FnOnce(&str) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        self.read(day)?.parse(parser)
+    }
+}
+
+/// Reads and parses the input of a day binary, honoring `--input <path>` and `--example` on the
+/// command line.
+pub fn load<T>(day: u8, parser: impl FnOnce(&str) -> Result<T, Error>) -> Result<T, Error> {
+    Args::from_env()?.load(day, parser)
+}
+
+/// Like [`load`], but prints the error and exits, which is all a day binary can do anyway.
+pub fn load_or_exit<T>(day: u8, parser: impl FnOnce(&str) -> Result<T, Error>) -> T {
+    load(day, parser).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        std::process::exit(1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{Args, InputKind, InputLocator, InputSource};
+    use crate::output::Format;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_locate_example() {
+        let path = InputLocator::new(4).locate(InputKind::Example).unwrap();
+        assert!(path.ends_with("day4/input_example.txt"));
+    }
+
+    #[test]
+    fn test_locate_dashed_example() {
+        let path = InputLocator::new(1).locate(InputKind::Example).unwrap();
+        assert!(path.ends_with("day1/input-example.txt"));
+    }
+
+    #[test]
+    fn test_missing_lists_candidates() {
+        let error = InputLocator::new(42).locate(InputKind::Real).unwrap_err();
+        assert!(
+            error
+                .message()
+                .starts_with("Could not find input for day 42, tried:")
+        );
+        assert!(error.message().contains("day42/input.txt"));
+    }
+
+    #[test]
+    fn test_explicit_path() {
+        let locator = InputLocator::new(4).with_path(Some(PathBuf::from("does/not/exist.txt")));
+        assert_eq!(
+            locator.candidates(InputKind::Real),
+            vec![PathBuf::from("does/not/exist.txt")]
+        );
+        assert!(locator.locate(InputKind::Real).is_err());
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = Args::parse(["-e", "--format", "json"].map(String::from)).unwrap();
+        assert_eq!(args.kind, InputKind::Example);
+        assert_eq!(args.format, Format::Json);
+        assert!(Args::parse(["--format", "xml"].map(String::from)).is_err());
+    }
+
+    #[test]
+    fn test_args_source() {
+        let source = |args: &[&str]| {
+            Args::parse(args.iter().map(|a| a.to_string()))
+                .unwrap()
+                .source()
+        };
+        assert_eq!(source(&[]), InputSource::Real);
+        assert_eq!(source(&["-e"]), InputSource::Example);
+        assert_eq!(source(&["--input", "-"]), InputSource::Stdin);
+        assert_eq!(
+            source(&["-e", "--input", "big.txt"]),
+            InputSource::File(PathBuf::from("big.txt"))
+        );
+    }
+}
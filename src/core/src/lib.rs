@@ -1,11 +1,32 @@
+pub mod bench;
 pub mod math;
 pub mod datastructures;
+pub mod error;
+pub mod example;
+pub mod input;
+pub mod log;
+pub mod output;
+pub mod parse;
+pub mod range;
+pub mod reader;
+pub mod scan;
+pub mod sections;
+pub mod solution;
 
-use std::fs::File;
-use std::io;
-use std::io::{BufRead, BufReader, Lines};
+pub use error::Error;
+pub use parse::{FromInput, parse};
+pub use range::RangeSpec;
+pub use reader::read_lines;
+pub use sections::{Section, sections};
 
-pub fn read_lines(path: &str) -> io::Result<Lines<BufReader<File>>> {
-    let file = File::open(path)?;
-    Ok(BufReader::new(file).lines())
+/// Parses every line with `parser`, attaching the line number and source line to any error.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parser: impl FnMut(&str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parser(line).map_err(|e| e.with_line(idx + 1, line)))
+        .collect()
 }
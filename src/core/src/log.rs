@@ -0,0 +1,204 @@
+use crate::error::Error;
+use std::env;
+use std::fmt::{Arguments, Display, Formatter};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Environment variable holding the log filter, see [`Filter`].
+pub const LOG_VAR: &str = "AOC_LOG";
+
+/// Writes a message to stderr if `level` is enabled for the calling crate, e.g. `day4`.
+///
+/// The arguments are only formatted when the message is written, so expensive dumps like a
+/// whole grid cost nothing while disabled.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        let target = ::std::module_path!().split("::").next().unwrap_or_default();
+        let level: $crate::log::Level = $level;
+        if $crate::log::enabled(target, level) {
+            $crate::log::write(target, level, ::std::format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str.trim() {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!(
+                "Invalid log level: {}, expected off, error, warn, info, debug or trace",
+                str
+            )),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// Which messages are written, parsed from a comma separated list like `info,day4=trace,day7=off`.
+///
+/// A bare level applies to every crate, `<crate>=<level>` overrides it for one crate. `off`
+/// disables logging. Without a filter only warnings and errors are written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Option<Level>)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self {
+            default: Some(Level::Warn),
+            targets: vec![],
+        }
+    }
+}
+
+impl Filter {
+    pub fn enabled(&self, target: &str, level: Level) -> bool {
+        let max = self
+            .targets
+            .iter()
+            .rfind(|(name, _)| name == target)
+            .map_or(self.default, |&(_, max)| max);
+        max.is_some_and(|max| level <= max)
+    }
+
+    /// The filter in [`LOG_VAR`], or the default if it is not set or invalid.
+    pub fn from_env() -> Filter {
+        let Ok(spec) = env::var(LOG_VAR) else {
+            return Filter::default();
+        };
+        spec.parse().unwrap_or_else(|error: Error| {
+            eprintln!("Ignoring {}: {}", LOG_VAR, error.message());
+            Filter::default()
+        })
+    }
+}
+
+impl FromStr for Filter {
+    type Err = Error;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let parse_level = |level: &str| match level.trim() {
+            "off" => Ok(None),
+            level => level.parse::<Level>().map(Some),
+        };
+
+        let mut filter = Filter::default();
+        for directive in spec
+            .split(',')
+            .filter(|directive| !directive.trim().is_empty())
+        {
+            match directive.split_once('=') {
+                Some((target, level)) => filter
+                    .targets
+                    .push((target.trim().to_string(), parse_level(level)?)),
+                None => filter.default = parse_level(directive)?,
+            }
+        }
+
+        Ok(filter)
+    }
+}
+
+static FILTER: OnceLock<Filter> = OnceLock::new();
+
+/// Sets the filter for the rest of the process, e.g. from a `--log` flag. Has to happen before
+/// the first message, otherwise the filter from [`LOG_VAR`] is already in place and this returns
+/// `false`.
+pub fn init(filter: Filter) -> bool {
+    FILTER.set(filter).is_ok()
+}
+
+pub fn enabled(target: &str, level: Level) -> bool {
+    FILTER.get_or_init(Filter::from_env).enabled(target, level)
+}
+
+pub fn write(target: &str, level: Level, message: Arguments) {
+    eprintln!("[{:<5} {}] {}", level, target, message);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::log::{Filter, Level};
+
+    #[test]
+    fn test_default_filter() {
+        let filter = Filter::default();
+        assert!(filter.enabled("day4", Level::Warn));
+        assert!(!filter.enabled("day4", Level::Info));
+    }
+
+    #[test]
+    fn test_filter_per_target() {
+        let filter: Filter = "info, day4=trace, day7=off".parse().unwrap();
+        assert!(filter.enabled("day1", Level::Info));
+        assert!(!filter.enabled("day1", Level::Debug));
+        assert!(filter.enabled("day4", Level::Trace));
+        assert!(!filter.enabled("day7", Level::Error));
+    }
+
+    #[test]
+    fn test_invalid_filter() {
+        let error = "day4=loud".parse::<Filter>().unwrap_err();
+        assert!(error.message().starts_with("Invalid log level: loud"));
+    }
+}
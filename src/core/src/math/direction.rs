@@ -0,0 +1,117 @@
+use crate::math::point2d::Point2D;
+
+/// A step on a grid. `y` grows downwards like the line numbers of an input, so `North` is the
+/// previous line.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four directions without diagonals, clockwise from `North`.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise from `North`.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    pub fn offset(&self) -> Point2D {
+        match self {
+            Direction::North => Point2D::new(0, -1),
+            Direction::NorthEast => Point2D::new(1, -1),
+            Direction::East => Point2D::new(1, 0),
+            Direction::SouthEast => Point2D::new(1, 1),
+            Direction::South => Point2D::new(0, 1),
+            Direction::SouthWest => Point2D::new(-1, 1),
+            Direction::West => Point2D::new(-1, 0),
+            Direction::NorthWest => Point2D::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        !Direction::CARDINAL.contains(self)
+    }
+
+    /// Rotates clockwise by `eighths` of a full turn.
+    fn rotate(&self, eighths: usize) -> Direction {
+        let idx = Direction::ALL
+            .iter()
+            .position(|dir| dir == self)
+            .unwrap_or(0);
+        Direction::ALL[(idx + eighths) % Direction::ALL.len()]
+    }
+
+    /// Rotates 90 degrees clockwise.
+    pub fn turn_right(&self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Rotates 90 degrees counterclockwise.
+    pub fn turn_left(&self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.rotate(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::direction::Direction;
+    use crate::math::point2d::Point2D;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::NorthEast.turn_right(), Direction::SouthEast);
+        assert_eq!(Direction::West.opposite(), Direction::East);
+        assert_eq!(Direction::SouthWest.opposite(), Direction::NorthEast);
+
+        for dir in Direction::ALL {
+            assert_eq!(dir.offset() + dir.opposite().offset(), Point2D::zero());
+            assert_eq!(dir.turn_left().turn_right(), dir);
+        }
+    }
+
+    #[test]
+    fn test_step() {
+        let point = Point2D::new(3, 3);
+        assert_eq!(point.step(Direction::North), Point2D::new(3, 2));
+        assert_eq!(point.step(Direction::SouthWest), Point2D::new(2, 4));
+    }
+
+    #[test]
+    fn test_neighbors_within() {
+        let corner = Point2D::new(0, 0);
+        assert_eq!(corner.neighbors4().count(), 4);
+        assert_eq!(
+            corner.neighbors4_within(3, 3).collect::<Vec<_>>(),
+            vec![Point2D::new(1, 0), Point2D::new(0, 1)]
+        );
+        assert_eq!(corner.neighbors8_within(3, 3).count(), 3);
+        assert_eq!(Point2D::new(1, 1).neighbors8_within(3, 3).count(), 8);
+        assert_eq!(Point2D::new(2, 1).neighbors8_within(3, 2).count(), 3);
+    }
+}
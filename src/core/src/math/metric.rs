@@ -0,0 +1,92 @@
+use crate::math::point::Point;
+
+/// An exact integer distance between points, so ordering by it never depends on rounding.
+pub trait Metric {
+    fn distance<const N: usize>(first: &Point<N>, second: &Point<N>) -> i64;
+
+    /// The distance, `None` if it does not fit in an `i64`.
+    fn checked_distance<const N: usize>(first: &Point<N>, second: &Point<N>) -> Option<i64>;
+
+    /// The distance computed in `u128`, for coordinates too large for [`Metric::distance`].
+    /// `None` only if even that overflows, which [`SquaredEuclidean`] can for huge coordinates.
+    fn checked_distance_wide<const N: usize>(first: &Point<N>, second: &Point<N>) -> Option<u128>;
+}
+
+/// Orders points exactly like the Euclidean distance, without the square root.
+pub struct SquaredEuclidean;
+
+pub struct Manhattan;
+
+pub struct Chebyshev;
+
+impl Metric for SquaredEuclidean {
+    fn distance<const N: usize>(first: &Point<N>, second: &Point<N>) -> i64 {
+        first.distance_sq(second)
+    }
+
+    fn checked_distance<const N: usize>(first: &Point<N>, second: &Point<N>) -> Option<i64> {
+        first.checked_distance_sq(second)
+    }
+
+    fn checked_distance_wide<const N: usize>(first: &Point<N>, second: &Point<N>) -> Option<u128> {
+        first.checked_distance_sq_wide(second)
+    }
+}
+
+impl Metric for Manhattan {
+    fn distance<const N: usize>(first: &Point<N>, second: &Point<N>) -> i64 {
+        first.manhattan(second)
+    }
+
+    fn checked_distance<const N: usize>(first: &Point<N>, second: &Point<N>) -> Option<i64> {
+        first.checked_manhattan(second)
+    }
+
+    fn checked_distance_wide<const N: usize>(first: &Point<N>, second: &Point<N>) -> Option<u128> {
+        Some(first.manhattan_wide(second))
+    }
+}
+
+impl Metric for Chebyshev {
+    fn distance<const N: usize>(first: &Point<N>, second: &Point<N>) -> i64 {
+        first.chebyshev(second)
+    }
+
+    fn checked_distance<const N: usize>(first: &Point<N>, second: &Point<N>) -> Option<i64> {
+        first.checked_chebyshev(second)
+    }
+
+    fn checked_distance_wide<const N: usize>(first: &Point<N>, second: &Point<N>) -> Option<u128> {
+        Some(first.chebyshev_wide(second))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::metric::{Chebyshev, Manhattan, Metric, SquaredEuclidean};
+    use crate::math::point2d::Point2D;
+    use crate::math::point3d::Point3D;
+
+    #[test]
+    fn test_metrics() {
+        let first = Point3D::new(1, -2, 3);
+        let second = Point3D::new(4, 2, 3);
+
+        assert_eq!(SquaredEuclidean::distance(&first, &second), 25);
+        assert_eq!(Manhattan::distance(&first, &second), 7);
+        assert_eq!(Chebyshev::distance(&first, &second), 4);
+    }
+
+    #[test]
+    fn test_squared_distance_is_exact() {
+        // 2^54 and 2^54 + 1 are the same as f64
+        let origin = Point2D::new(0, 0);
+        let near = Point2D::new(1 << 27, 0);
+        let far = Point2D::new(1 << 27, 1);
+
+        assert_eq!(origin.distance(&near), origin.distance(&far));
+        assert!(
+            SquaredEuclidean::distance(&origin, &near) < SquaredEuclidean::distance(&origin, &far)
+        );
+    }
+}
@@ -1,2 +1,5 @@
+pub mod direction;
+pub mod metric;
+pub mod point;
 pub mod point2d;
 pub mod point3d;
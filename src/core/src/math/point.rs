@@ -0,0 +1,647 @@
+use crate::error::Error;
+use crate::scan;
+use std::array;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+/// Names of the first axes, used by [`Display`].
+const AXES: [&str; 4] = ["x", "y", "z", "w"];
+
+/// A point or vector with `N` integer coordinates, see [`Point2D`](crate::math::point2d::Point2D)
+/// and [`Point3D`](crate::math::point3d::Point3D) for the usual cases.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Point<const N: usize> {
+    pub coords: [i64; N],
+}
+
+impl<const N: usize> Point<N> {
+    pub const fn from_coords(coords: [i64; N]) -> Self {
+        Self { coords }
+    }
+
+    pub const fn zero() -> Self {
+        Self { coords: [0; N] }
+    }
+
+    /// Parses `N` comma separated coordinates, see [`Point::parse_with`].
+    pub fn parse(str: &str) -> Result<Self, Error> {
+        Self::parse_with(str, ',')
+    }
+
+    /// Parses `N` coordinates separated by `separator`, e.g. `162,817,812`, `(3, 4)` or `<1, -2>`.
+    ///
+    /// Whitespace around the coordinates and one pair of enclosing parentheses or angle brackets
+    /// are ignored. A whitespace separator splits on any run of whitespace.
+    pub fn parse_with(str: &str, separator: char) -> Result<Self, Error> {
+        let trimmed = str.trim();
+        let inner = [('(', ')'), ('<', '>')]
+            .iter()
+            .find_map(|&(open, close)| trimmed.strip_prefix(open)?.strip_suffix(close))
+            .unwrap_or(trimmed);
+
+        let fields: Vec<&str> = match separator.is_whitespace() {
+            true => inner.split_whitespace().collect(),
+            false => inner.split(separator).map(str::trim).collect(),
+        };
+        if fields.len() != N {
+            return Err(Error::at_slice(
+                format!(
+                    "Expected {} coordinates separated by {:?}, found {}",
+                    N,
+                    separator,
+                    fields.len()
+                ),
+                str,
+                inner,
+            ));
+        }
+
+        let mut coords = [0; N];
+        for (coord, field) in coords.iter_mut().zip(fields) {
+            *coord = scan::field(str, field)?;
+        }
+
+        Ok(Self { coords })
+    }
+
+    pub fn map(&self, f: impl Fn(i64) -> i64) -> Self {
+        Self {
+            coords: self.coords.map(f),
+        }
+    }
+
+    /// Combines the coordinates of both points pairwise.
+    pub fn zip_map(&self, other: &Self, f: impl Fn(i64, i64) -> i64) -> Self {
+        Self {
+            coords: array::from_fn(|idx| f(self.coords[idx], other.coords[idx])),
+        }
+    }
+
+    /// Like [`Point::map`], `None` as soon as `f` fails for a coordinate.
+    pub fn checked_map(&self, f: impl Fn(i64) -> Option<i64>) -> Option<Self> {
+        let mut coords = [0; N];
+        for (coord, &value) in coords.iter_mut().zip(&self.coords) {
+            *coord = f(value)?;
+        }
+        Some(Self { coords })
+    }
+
+    /// Like [`Point::zip_map`], `None` as soon as `f` fails for a pair of coordinates.
+    pub fn checked_zip_map(
+        &self,
+        other: &Self,
+        f: impl Fn(i64, i64) -> Option<i64>,
+    ) -> Option<Self> {
+        let mut coords = [0; N];
+        for (idx, coord) in coords.iter_mut().enumerate() {
+            *coord = f(self.coords[idx], other.coords[idx])?;
+        }
+        Some(Self { coords })
+    }
+
+    /// Smallest value of every coordinate, unlike [`Ord::min`] which picks one of the points.
+    pub fn component_min(&self, other: &Self) -> Self {
+        self.zip_map(other, i64::min)
+    }
+
+    /// Largest value of every coordinate, unlike [`Ord::max`] which picks one of the points.
+    pub fn component_max(&self, other: &Self) -> Self {
+        self.zip_map(other, i64::max)
+    }
+
+    pub fn abs(&self) -> Self {
+        self.map(i64::abs)
+    }
+
+    pub fn signum(&self) -> Self {
+        self.map(i64::signum)
+    }
+
+    pub fn dot(&self, other: &Self) -> i64 {
+        self.coords
+            .iter()
+            .zip(other.coords)
+            .map(|(left, right)| left * right)
+            .sum()
+    }
+
+    /// [`Point::dot`], `None` if any product or the sum does not fit in an `i64`.
+    pub fn checked_dot(&self, other: &Self) -> Option<i64> {
+        self.coords
+            .iter()
+            .zip(other.coords)
+            .try_fold(0i64, |sum, (left, right)| {
+                sum.checked_add(left.checked_mul(right)?)
+            })
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        self.checked_zip_map(other, i64::checked_add)
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_zip_map(other, i64::checked_sub)
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        self.checked_map(i64::checked_neg)
+    }
+
+    pub fn checked_mul(&self, factor: i64) -> Option<Self> {
+        self.checked_map(|coord| coord.checked_mul(factor))
+    }
+
+    pub fn checked_div(&self, divisor: i64) -> Option<Self> {
+        self.checked_map(|coord| coord.checked_div(divisor))
+    }
+
+    pub fn checked_pow(&self, exponent: u32) -> Option<Self> {
+        self.checked_map(|coord| coord.checked_pow(exponent))
+    }
+
+    pub fn checked_sum(&self) -> Option<i64> {
+        self.coords
+            .iter()
+            .try_fold(0i64, |sum, &coord| sum.checked_add(coord))
+    }
+
+    pub fn saturating_add(&self, other: &Self) -> Self {
+        self.zip_map(other, i64::saturating_add)
+    }
+
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        self.zip_map(other, i64::saturating_sub)
+    }
+
+    pub fn saturating_mul(&self, factor: i64) -> Self {
+        self.map(|coord| coord.saturating_mul(factor))
+    }
+
+    /// The coordinates widened to `i128`, in which differences of `i64` coordinates never overflow.
+    pub fn wide(&self) -> [i128; N] {
+        self.coords.map(i128::from)
+    }
+
+    /// Pairwise absolute differences of the coordinates, which always fit in a `u64`.
+    fn wide_differences(&self, other: &Self) -> impl Iterator<Item = u128> {
+        self.coords
+            .iter()
+            .zip(other.coords)
+            .map(|(left, right)| u128::from(left.abs_diff(right)))
+    }
+
+    /// Euclidean distance as a float, so only approximate for large distances. Computed in `f64`
+    /// and never overflows. Prefer [`Point::distance_sq`] for comparing distances.
+    pub fn distance(&self, other: &Self) -> f64 {
+        self.coords
+            .iter()
+            .zip(other.coords)
+            .map(|(left, right)| (left.abs_diff(right) as f64).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Square of the Euclidean distance, which orders points the same way but stays exact.
+    pub fn distance_sq(&self, other: &Self) -> i64 {
+        (self - other).pow(2).sum()
+    }
+
+    /// Sum of the absolute differences of the coordinates.
+    pub fn manhattan(&self, other: &Self) -> i64 {
+        (self - other).abs().sum()
+    }
+
+    /// Largest absolute difference of any coordinate, i.e. the number of king moves.
+    pub fn chebyshev(&self, other: &Self) -> i64 {
+        (self - other).abs().coords.into_iter().max().unwrap_or(0)
+    }
+
+    /// [`Point::distance_sq`], `None` if it does not fit in an `i64`.
+    pub fn checked_distance_sq(&self, other: &Self) -> Option<i64> {
+        self.checked_sub(other)?.checked_pow(2)?.checked_sum()
+    }
+
+    /// [`Point::manhattan`], `None` if it does not fit in an `i64`.
+    pub fn checked_manhattan(&self, other: &Self) -> Option<i64> {
+        self.checked_sub(other)?
+            .checked_map(i64::checked_abs)?
+            .checked_sum()
+    }
+
+    /// [`Point::chebyshev`], `None` if it does not fit in an `i64`.
+    pub fn checked_chebyshev(&self, other: &Self) -> Option<i64> {
+        let distance = self.chebyshev_wide(other);
+        i64::try_from(distance).ok()
+    }
+
+    /// [`Point::distance_sq`] computed in `u128`, `None` if even that overflows. Two dimensions of
+    /// differences up to 2^63 always fit.
+    pub fn checked_distance_sq_wide(&self, other: &Self) -> Option<u128> {
+        self.wide_differences(other)
+            .try_fold(0u128, |sum, difference| {
+                sum.checked_add(difference.checked_mul(difference)?)
+            })
+    }
+
+    /// [`Point::manhattan`] computed in `u128`, exact for all coordinates.
+    pub fn manhattan_wide(&self, other: &Self) -> u128 {
+        self.wide_differences(other).sum()
+    }
+
+    /// [`Point::chebyshev`] computed in `u128`, exact for all coordinates.
+    pub fn chebyshev_wide(&self, other: &Self) -> u128 {
+        self.wide_differences(other).max().unwrap_or(0)
+    }
+
+    /// [`Point::distance_sq`], clamped to `i64::MAX` if it does not fit.
+    pub fn saturating_distance_sq(&self, other: &Self) -> i64 {
+        self.checked_distance_sq_wide(other)
+            .map_or(i64::MAX, saturate)
+    }
+
+    /// [`Point::manhattan`], clamped to `i64::MAX` if it does not fit.
+    pub fn saturating_manhattan(&self, other: &Self) -> i64 {
+        saturate(self.manhattan_wide(other))
+    }
+
+    /// [`Point::chebyshev`], clamped to `i64::MAX` if it does not fit.
+    pub fn saturating_chebyshev(&self, other: &Self) -> i64 {
+        saturate(self.chebyshev_wide(other))
+    }
+
+    pub fn pow(&self, exponent: u32) -> Self {
+        self.map(|coord| coord.pow(exponent))
+    }
+
+    pub fn sum(&self) -> i64 {
+        self.coords.iter().sum()
+    }
+}
+
+fn saturate(value: u128) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+impl<const N: usize> FromStr for Point<N> {
+    type Err = Error;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        Self::parse(str)
+    }
+}
+
+/// Reading order: the last coordinate decides first, so 2D points sort by `y` and then by `x`,
+/// like the lines and columns of an input.
+impl<const N: usize> Ord for Point<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.coords.iter().rev().cmp(other.coords.iter().rev())
+    }
+}
+
+impl<const N: usize> PartialOrd for Point<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Default for Point<N> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const N: usize> From<[i64; N]> for Point<N> {
+    fn from(coords: [i64; N]) -> Self {
+        Self { coords }
+    }
+}
+
+impl<const N: usize> From<Point<N>> for [i64; N] {
+    fn from(point: Point<N>) -> Self {
+        point.coords
+    }
+}
+
+impl From<(i64, i64)> for Point<2> {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self { coords: [x, y] }
+    }
+}
+
+impl From<Point<2>> for (i64, i64) {
+    fn from(point: Point<2>) -> Self {
+        let [x, y] = point.coords;
+        (x, y)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point<3> {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Self { coords: [x, y, z] }
+    }
+}
+
+impl From<Point<3>> for (i64, i64, i64) {
+    fn from(point: Point<3>) -> Self {
+        let [x, y, z] = point.coords;
+        (x, y, z)
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = i64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.coords[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.coords[index]
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.zip_map(&other, |left, right| left + right)
+    }
+}
+
+impl<const N: usize> Add for &Point<N> {
+    type Output = Point<N>;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.zip_map(other, |left, right| left + right)
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.zip_map(&other, |left, right| left - right)
+    }
+}
+
+impl<const N: usize> Sub for &Point<N> {
+    type Output = Point<N>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.zip_map(other, |left, right| left - right)
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|coord| -coord)
+    }
+}
+
+impl<const N: usize> Neg for &Point<N> {
+    type Output = Point<N>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|coord| -coord)
+    }
+}
+
+impl<const N: usize> Mul<i64> for Point<N> {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self::Output {
+        self.map(|coord| coord * factor)
+    }
+}
+
+impl<const N: usize> Mul<Point<N>> for i64 {
+    type Output = Point<N>;
+
+    fn mul(self, point: Point<N>) -> Self::Output {
+        point * self
+    }
+}
+
+impl<const N: usize> Div<i64> for Point<N> {
+    type Output = Self;
+
+    fn div(self, divisor: i64) -> Self::Output {
+        self.map(|coord| coord / divisor)
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const N: usize> MulAssign<i64> for Point<N> {
+    fn mul_assign(&mut self, factor: i64) {
+        *self = *self * factor;
+    }
+}
+
+impl<const N: usize> DivAssign<i64> for Point<N> {
+    fn div_assign(&mut self, divisor: i64) {
+        *self = *self / divisor;
+    }
+}
+
+impl<const N: usize> Display for Point<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (idx, coord) in self.coords.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            match AXES.get(idx).filter(|_| N <= AXES.len()) {
+                Some(axis) => write!(f, "{}: {}", axis, coord)?,
+                None => write!(f, "{}", coord)?,
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::point::Point;
+    use crate::math::point2d::Point2D;
+    use crate::math::point3d::Point3D;
+
+    #[test]
+    fn test_operators() {
+        let mut point = Point3D::new(1, -2, 3);
+        let other = Point3D::new(4, 5, -6);
+
+        assert_eq!(point + other, Point3D::new(5, 3, -3));
+        assert_eq!(point - other, Point3D::new(-3, -7, 9));
+        assert_eq!(-point, Point3D::new(-1, 2, -3));
+        assert_eq!(point * 2, 2 * point);
+        assert_eq!(Point2D::new(7, -7) / 2, Point2D::new(3, -3));
+
+        point += other;
+        point -= Point3D::new(1, 1, 1);
+        assert_eq!(point, Point3D::new(4, 2, -4));
+        point[2] = 0;
+        assert_eq!(point.z(), 0);
+    }
+
+    #[test]
+    fn test_component_wise() {
+        let point = Point2D::new(-3, 4);
+        let other = Point2D::new(2, -5);
+
+        assert_eq!(point.component_min(&other), Point2D::new(-3, -5));
+        assert_eq!(point.component_max(&other), Point2D::new(2, 4));
+        assert_eq!(point.abs(), Point2D::new(3, 4));
+        assert_eq!(point.signum(), Point2D::new(-1, 1));
+        assert_eq!(point.dot(&other), -26);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let point: Point<4> = [1, 2, 3, 4].into();
+        assert_eq!(<[i64; 4]>::from(point), [1, 2, 3, 4]);
+        assert_eq!(point.to_string(), "{x: 1, y: 2, z: 3, w: 4}");
+        assert_eq!(Point3D::from((1, 2, 3)), Point3D::new(1, 2, 3));
+        assert_eq!(<(i64, i64)>::from(Point2D::new(5, 6)), (5, 6));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Point::<4>::parse("1,-2,3,4").unwrap(),
+            Point::from_coords([1, -2, 3, 4])
+        );
+
+        let error = Point3D::parse("1,x,3").unwrap_err();
+        assert_eq!(error.column(), Some(3));
+        assert!(Point2D::parse("1,2,3").is_err());
+    }
+
+    #[test]
+    fn test_parse_flexible() {
+        let expected = Point2D::new(3, -4);
+        for str in ["3,-4", " 3 , -4 ", "(3, -4)", "<3,-4>", "< 3, -4 >"] {
+            assert_eq!(str.parse::<Point2D>().unwrap(), expected, "{}", str);
+        }
+
+        assert_eq!(Point2D::parse_with("3;-4", ';').unwrap(), expected);
+        assert_eq!(
+            Point3D::parse_with("(1  2\t3)", ' ').unwrap(),
+            Point3D::new(1, 2, 3)
+        );
+
+        let error = "(3, -4".parse::<Point2D>().unwrap_err();
+        assert_eq!(error.column(), Some(1));
+        assert!(error.message().starts_with("Invalid value `(3`"));
+        let error = "<1, 2>".parse::<Point3D>().unwrap_err();
+        assert!(
+            error
+                .message()
+                .starts_with("Expected 3 coordinates separated by ','")
+        );
+    }
+
+    #[test]
+    fn test_reading_order() {
+        let mut points = vec![
+            Point2D::new(5, 1),
+            Point2D::new(0, 2),
+            Point2D::new(2, 1),
+            Point2D::new(9, 0),
+        ];
+        points.sort();
+        assert_eq!(
+            points,
+            vec![
+                Point2D::new(9, 0),
+                Point2D::new(2, 1),
+                Point2D::new(5, 1),
+                Point2D::new(0, 2)
+            ]
+        );
+        assert!(Point3D::new(0, 0, 1) > Point3D::new(5, 5, 0));
+    }
+
+    #[test]
+    fn test_checked_operations() {
+        let max = Point2D::new(i64::MAX, 0);
+        let one = Point2D::new(1, 1);
+
+        assert_eq!(max.checked_add(&one), None);
+        assert_eq!(one.checked_add(&one), Some(Point2D::new(2, 2)));
+        assert_eq!(Point2D::new(i64::MIN, 0).checked_neg(), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(max.checked_dot(&one), Some(i64::MAX));
+        assert_eq!(max.checked_dot(&Point2D::new(2, 0)), None);
+        assert_eq!(Point2D::new(i64::MAX, 1).checked_dot(&one), None);
+        assert_eq!(one.checked_div(0), None);
+        assert_eq!(max.saturating_add(&one).checked_sum(), None);
+        assert_eq!(max.saturating_add(&one), Point2D::new(i64::MAX, 1));
+        assert_eq!(
+            Point2D::new(i64::MIN, 3).saturating_mul(-2),
+            Point2D::new(i64::MAX, -6)
+        );
+    }
+
+    #[test]
+    fn test_distances_with_large_coordinates() {
+        let first = Point3D::new(1 << 40, -(1 << 40), 0);
+        let second = Point3D::new(-(1 << 40), 1 << 40, 3);
+
+        assert_eq!(first.checked_distance_sq(&second), None);
+        assert_eq!(
+            first.checked_distance_sq_wide(&second),
+            Some(2 * (1u128 << 82) + 9)
+        );
+        assert_eq!(first.checked_manhattan(&second), Some(1 << 42 | 3));
+
+        let min = Point2D::new(i64::MIN, 0);
+        let max = Point2D::new(i64::MAX, 0);
+        assert_eq!(min.checked_chebyshev(&max), None);
+        assert_eq!(min.chebyshev_wide(&max), u128::from(u64::MAX));
+        assert_eq!(min.manhattan_wide(&max), u128::from(u64::MAX));
+    }
+
+    #[test]
+    fn test_distances_with_extreme_coordinates() {
+        let min = Point2D::new(i64::MIN, 0);
+        let max = Point2D::new(i64::MAX, 0);
+        assert_eq!(
+            min.checked_distance_sq_wide(&max),
+            Some(u128::from(u64::MAX).pow(2))
+        );
+        assert_eq!(min.distance(&max), u64::MAX as f64);
+
+        let corner = Point2D::new(i64::MIN, i64::MIN);
+        let opposite = Point2D::new(i64::MAX, i64::MAX);
+        assert_eq!(corner.checked_distance_sq_wide(&opposite), None);
+        assert_eq!(corner.manhattan_wide(&opposite), 2 * u128::from(u64::MAX));
+        assert!(corner.distance(&opposite).is_finite());
+        assert_eq!(corner.saturating_distance_sq(&opposite), i64::MAX);
+        assert_eq!(corner.saturating_manhattan(&opposite), i64::MAX);
+        assert_eq!(corner.saturating_chebyshev(&opposite), i64::MAX);
+
+        let near = Point2D::new(3, -4);
+        assert_eq!(near.saturating_distance_sq(&Point2D::zero()), 25);
+        assert_eq!(near.saturating_manhattan(&Point2D::zero()), 7);
+        assert_eq!(near.saturating_chebyshev(&Point2D::zero()), 4);
+    }
+}
@@ -1,92 +1,53 @@
-use std::fmt::{Display, Formatter};
-use std::ops::{Add, Sub};
+use crate::math::direction::Direction;
+use crate::math::point::Point;
 
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
-pub struct Point2D {
-    pub x: i64,
-    pub y: i64,
-}
+pub type Point2D = Point<2>;
 
 impl Point2D {
-    pub fn new(x: i64, y: i64) -> Self {
-        Self { x, y}
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { coords: [x, y] }
     }
 
-    pub fn parse(str: &str) -> Result<Point2D, String> {
-        let split: Vec<&str> = str.split(',').collect();
-        if let [x, y] = split[..] {
-            Ok(Self {
-                x: x.parse().map_err(|_| format!("Invalid x: {}", x))?,
-                y: y.parse().map_err(|_| format!("Invalid y: {}", y))?,
-            })
-        } else {
-            Err(format!("Wrong input format: {}, expected x,y,z", str))
-        }
+    pub const fn x(&self) -> i64 {
+        self.coords[0]
     }
 
-    pub fn distance(&self, other: &Self) -> f64 {
-        let sum = (self - other).pow(2).sum();
-        (sum as f64).sqrt()
+    pub const fn y(&self) -> i64 {
+        self.coords[1]
     }
 
-    pub fn pow(&self, exponent: u32) -> Self {
-        Self {
-            x: self.x.pow(exponent),
-            y: self.y.pow(exponent),
-        }
+    pub fn step(&self, direction: Direction) -> Point2D {
+        self + &direction.offset()
     }
 
-    pub fn sum(&self) -> i64 {
-        self.x + self.y
+    /// The points above, right, below and left of this one.
+    pub fn neighbors4(&self) -> impl Iterator<Item = Point2D> {
+        let point = *self;
+        Direction::CARDINAL
+            .into_iter()
+            .map(move |dir| point.step(dir))
     }
-}
-
-impl Sub for Point2D {
-    type Output = Self;
 
-    fn sub(self, other: Self) -> Self::Output {
-        Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
+    /// The eight points around this one, diagonals included.
+    pub fn neighbors8(&self) -> impl Iterator<Item = Point2D> {
+        let point = *self;
+        Direction::ALL.into_iter().map(move |dir| point.step(dir))
     }
-}
-
-impl Sub for &Point2D {
-    type Output = Point2D;
 
-    fn sub(self, other: Self) -> Self::Output {
-        Self::Output {
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
+    /// Whether the point lies on a grid of `width` by `height` starting at the origin.
+    pub fn is_within(&self, width: usize, height: usize) -> bool {
+        (0..width as i64).contains(&self.x()) && (0..height as i64).contains(&self.y())
     }
-}
 
-impl Add for Point2D {
-    type Output = Self;
-
-    fn add(self, other: Self) -> Self::Output {
-        Self {
-            x: self.x + other.x,
-            y: self.y + other.y,
-        }
+    /// [`Point2D::neighbors4`] that lie on a grid of `width` by `height`.
+    pub fn neighbors4_within(&self, width: usize, height: usize) -> impl Iterator<Item = Point2D> {
+        self.neighbors4()
+            .filter(move |point| point.is_within(width, height))
     }
-}
-
-impl Add for &Point2D {
-    type Output = Point2D;
 
-    fn add(self, other: Self) -> Self::Output {
-        Self::Output {
-            x: self.x + other.x,
-            y: self.y + other.y,
-        }
+    /// [`Point2D::neighbors8`] that lie on a grid of `width` by `height`.
+    pub fn neighbors8_within(&self, width: usize, height: usize) -> impl Iterator<Item = Point2D> {
+        self.neighbors8()
+            .filter(move |point| point.is_within(width, height))
     }
 }
-
-impl Display for Point2D {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{x: {}, y: {}}}", self.x, self.y)
-    }
-}
\ No newline at end of file
@@ -0,0 +1,394 @@
+use crate::error::{Error, panic_message};
+use crate::input::{Args, InputSource};
+use crate::solution::{Params, Part};
+use std::cell::{Cell, OnceCell};
+use std::fmt::Display;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum Format {
+    /// Free text meant for people, each day labels its answers its own way.
+    #[default]
+    Text,
+    /// One JSON object per line and part, see [`Record`].
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Invalid format: {}, expected text or json", str)),
+        }
+    }
+}
+
+/// Result of solving one part, as printed with `--format json`:
+///
+/// ```text
+/// {"day":8,"part":1,"input":"real","status":"ok","answer":"131580","time_ns":41237}
+/// {"day":8,"part":2,"input":"real","status":"error","error":"panicked: ...","time_ns":10}
+/// {"day":8,"part":1,"input":"file","path":"big.txt","status":"ok","answer":"40","time_ns":9}
+/// ```
+///
+/// `input` is `real`, `example`, `stdin` or `file`, the latter together with the `path`.
+///
+/// Answers are always strings, so large numbers survive parsers that read numbers as floats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub input: InputSource,
+    pub answer: Result<String, String>,
+    pub time: Duration,
+}
+
+impl Record {
+    pub fn to_json(&self) -> String {
+        let (status, key, value) = match self.answer {
+            Ok(ref answer) => ("ok", "answer", answer),
+            Err(ref error) => ("error", "error", error),
+        };
+
+        let path = self.input.path().map_or(String::new(), |path| {
+            format!(",\"path\":{}", json_string(&path.display().to_string()))
+        });
+
+        format!(
+            "{{\"day\":{},\"part\":{},\"input\":\"{}\"{},\"status\":\"{}\",\"{}\":{},\"time_ns\":{}}}",
+            self.day,
+            self.part,
+            self.input.name(),
+            path,
+            status,
+            key,
+            json_string(value),
+            self.time.as_nanos()
+        )
+    }
+}
+
+impl FromStr for Record {
+    type Err = Error;
+
+    /// Reads a line printed by [`Record::to_json`] back.
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields = json_fields(line)?;
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+                .ok_or_else(|| Error::new(format!("Missing field {}", name)))
+        };
+
+        let answer = match field("status")? {
+            "ok" => Ok(field("answer")?.to_string()),
+            "error" => Err(field("error")?.to_string()),
+            status => return Err(Error::new(format!("Invalid status: {}", status))),
+        };
+
+        Ok(Record {
+            day: field("day")?.parse()?,
+            part: field("part")?.parse::<Part>()?,
+            input: match field("input")? {
+                "real" => InputSource::Real,
+                "example" => InputSource::Example,
+                "stdin" => InputSource::Stdin,
+                "file" => InputSource::File(PathBuf::from(field("path")?)),
+                input => return Err(Error::new(format!("Invalid input: {}", input))),
+            },
+            answer,
+            time: Duration::from_nanos(field("time_ns")?.parse()?),
+        })
+    }
+}
+
+/// Key value pairs of a flat JSON object whose values are strings or numbers.
+fn json_fields(line: &str) -> Result<Vec<(String, String)>, Error> {
+    let inner = line
+        .trim()
+        .strip_prefix('{')
+        .and_then(|line| line.strip_suffix('}'))
+        .ok_or_else(|| Error::new(format!("Expected a JSON object: {}", line)))?;
+
+    let mut fields = vec![];
+    let mut chars = inner.chars().peekable();
+    loop {
+        while chars
+            .next_if(|chr| chr.is_whitespace() || *chr == ',')
+            .is_some()
+        {}
+        if chars.peek().is_none() {
+            return Ok(fields);
+        }
+
+        let key = json_value(&mut chars)?;
+        while chars.next_if(|chr| chr.is_whitespace()).is_some() {}
+        if chars.next() != Some(':') {
+            return Err(Error::new(format!("Expected : after {}", key)));
+        }
+        while chars.next_if(|chr| chr.is_whitespace()).is_some() {}
+        let value = json_value(&mut chars)?;
+        fields.push((key, value));
+    }
+}
+
+fn json_value(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<String, Error> {
+    if chars.next_if_eq(&'"').is_none() {
+        let mut value = String::new();
+        while let Some(chr) = chars.next_if(|chr| *chr != ',' && *chr != ':') {
+            value.push(chr);
+        }
+        return Ok(value.trim().to_string());
+    }
+
+    let mut value = String::new();
+    while let Some(chr) = chars.next() {
+        match chr {
+            '"' => return Ok(value),
+            '\\' => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('r') => value.push('\r'),
+                Some('t') => value.push('\t'),
+                Some('u') => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    let chr = u32::from_str_radix(&code, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| Error::new(format!("Invalid escape \\u{}", code)))?;
+                    value.push(chr);
+                }
+                Some(chr) => value.push(chr),
+                None => break,
+            },
+            chr => value.push(chr),
+        }
+    }
+
+    Err(Error::new("Unterminated string"))
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for chr in value.chars() {
+        match chr {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            chr if chr.is_control() => escaped.push_str(&format!("\\u{:04x}", chr as u32)),
+            chr => escaped.push(chr),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Prints the answers of a day binary in the format chosen on the command line.
+pub struct Output {
+    day: u8,
+    args: Args,
+    params: OnceCell<[Params; 2]>,
+    failed: Cell<bool>,
+}
+
+impl Output {
+    pub fn new(day: u8, args: Args) -> Self {
+        Self {
+            day,
+            args,
+            params: OnceCell::new(),
+            failed: Cell::new(false),
+        }
+    }
+
+    /// Reads the command line, printing the error and exiting if it is invalid.
+    pub fn from_env_or_exit(day: u8) -> Self {
+        let args = Args::from_env().unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            std::process::exit(1)
+        });
+        Output::new(day, args)
+    }
+
+    pub fn format(&self) -> Format {
+        self.args.format
+    }
+
+    pub fn is_text(&self) -> bool {
+        self.format() == Format::Text
+    }
+
+    /// Reads and parses the input, exiting if that fails. With `--format json` the failure is
+    /// reported as an error record for both parts first.
+    ///
+    /// The [`Params`] of the input, e.g. those of an annotated example, are kept for
+    /// [`Output::params`].
+    pub fn load_or_exit<T>(&self, parser: impl FnOnce(&str) -> Result<T, Error>) -> T {
+        let loaded = self.args.read(self.day).and_then(|input| {
+            let params = [input.params(Part::One)?, input.params(Part::Two)?];
+            Ok((input.parse(parser)?, params))
+        });
+
+        match loaded {
+            Ok((parsed, params)) => {
+                let _ = self.params.set(params);
+                parsed
+            }
+            Err(error) => {
+                match self.format() {
+                    Format::Text => eprintln!("{}", error),
+                    Format::Json => {
+                        for part in Part::ALL {
+                            self.print(part, Err(error.to_string()), Duration::ZERO);
+                        }
+                    }
+                }
+                std::process::exit(1)
+            }
+        }
+    }
+
+    /// Parameters for solving `part` of the loaded input, empty before [`Output::load_or_exit`].
+    pub fn params(&self, part: Part) -> Params {
+        self.params
+            .get()
+            .map(|params| params[part.number() as usize - 1].clone())
+            .unwrap_or_default()
+    }
+
+    /// Solves a part and prints its answer, after `label` for text. With `--format json` a panic
+    /// is reported as an error record and the remaining parts still run.
+    pub fn part<A: Display>(&self, part: Part, label: &str, solve: impl FnOnce() -> A) {
+        self.try_part(part, label, || Ok::<A, Error>(solve()));
+    }
+
+    /// Like [`Output::part`] for parts that can fail, e.g. on invalid [`Params`]. A returned error
+    /// is reported like a panic.
+    pub fn try_part<A: Display>(
+        &self,
+        part: Part,
+        label: &str,
+        solve: impl FnOnce() -> Result<A, Error>,
+    ) {
+        match self.format() {
+            Format::Text => match solve() {
+                Ok(answer) => println!("{}{}", label, answer),
+                Err(error) => {
+                    eprintln!("{}", error);
+                    self.failed.set(true);
+                }
+            },
+            Format::Json => {
+                let start = Instant::now();
+                let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+                    solve()
+                        .map(|answer| answer.to_string())
+                        .map_err(|error| error.to_string())
+                }))
+                .unwrap_or_else(|payload| Err(format!("panicked: {}", panic_message(&*payload))));
+                self.print(part, answer, start.elapsed());
+            }
+        }
+    }
+
+    fn print(&self, part: Part, answer: Result<String, String>, time: Duration) {
+        if answer.is_err() {
+            self.failed.set(true);
+        }
+
+        let record = Record {
+            day: self.day,
+            part,
+            input: self.args.source(),
+            answer,
+            time,
+        };
+        println!("{}", record.to_json());
+    }
+
+    /// Exits with an error code if any part failed.
+    pub fn finish(self) {
+        if self.failed.get() {
+            std::process::exit(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::InputSource;
+    use crate::output::Record;
+    use crate::solution::Part;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    #[test]
+    fn test_record_to_json() {
+        let record = Record {
+            day: 8,
+            part: Part::Two,
+            input: InputSource::Example,
+            answer: Ok("25272".to_string()),
+            time: Duration::from_micros(3),
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":8,"part":2,"input":"example","status":"ok","answer":"25272","time_ns":3000}"#
+        );
+    }
+
+    #[test]
+    fn test_error_record_is_escaped() {
+        let record = Record {
+            day: 1,
+            part: Part::One,
+            input: InputSource::Real,
+            answer: Err("panicked: \"bad\"\nline".to_string()),
+            time: Duration::ZERO,
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":1,"part":1,"input":"real","status":"error","error":"panicked: \"bad\"\nline","time_ns":0}"#
+        );
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let record = Record {
+            day: 7,
+            part: Part::One,
+            input: InputSource::Real,
+            answer: Err("panicked: \"bad\", \\ at\n\u{1}".to_string()),
+            time: Duration::from_nanos(1234),
+        };
+        assert_eq!(Record::from_str(&record.to_json()).unwrap(), record);
+    }
+
+    #[test]
+    fn test_file_record_has_path() {
+        let record = Record {
+            day: 3,
+            part: Part::One,
+            input: InputSource::File(PathBuf::from("inputs/big.txt")),
+            answer: Ok("17".to_string()),
+            time: Duration::ZERO,
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":3,"part":1,"input":"file","path":"inputs/big.txt","status":"ok","answer":"17","time_ns":0}"#
+        );
+        assert_eq!(Record::from_str(&record.to_json()).unwrap(), record);
+    }
+}
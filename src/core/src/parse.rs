@@ -0,0 +1,183 @@
+use crate::datastructures::grid::Grid;
+use crate::error::Error;
+use crate::sections::sections;
+use std::str::FromStr;
+
+/// Types that can be built from a whole puzzle input.
+///
+/// Combine the built-in impls to describe the shape of an input, e.g.
+/// `(Vec<Range>, Vec<i64>)` for a block of ranges followed by a blank line and a block of ids.
+pub trait FromInput: Sized {
+    fn from_input(input: &str) -> Result<Self, Error>;
+}
+
+pub fn parse<T: FromInput>(input: &str) -> Result<T, Error> {
+    T::from_input(input)
+}
+
+/// One value per line.
+impl<T> FromInput for Vec<T>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    fn from_input(input: &str) -> Result<Self, Error> {
+        crate::parse_lines(input, |line| {
+            line.parse::<T>()
+                .map_err(|e| e.into().or_at(1, line.chars().count()))
+        })
+    }
+}
+
+/// Comma separated values, possibly spread over several lines. Empty entries, like the one after
+/// a trailing comma, are skipped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comma<T>(pub Vec<T>);
+
+impl<T> FromInput for Comma<T>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    fn from_input(input: &str) -> Result<Self, Error> {
+        let mut values: Vec<T> = vec![];
+
+        for (idx, line) in input.lines().enumerate() {
+            for part in line.split(',') {
+                let trimmed = part.trim();
+                if trimmed.is_empty() {
+                    continue;
+                }
+
+                let value = trimmed.parse::<T>().map_err(|e| {
+                    let offset = trimmed.as_ptr() as usize - line.as_ptr() as usize;
+                    e.into()
+                        .or_at(1, trimmed.chars().count())
+                        .with_offset(line[..offset].chars().count())
+                        .with_line(idx + 1, line)
+                })?;
+                values.push(value);
+            }
+        }
+
+        Ok(Comma(values))
+    }
+}
+
+/// Grid of characters, each converted with `TryFrom<char>`. All lines have to be equally long.
+impl<T: TryFrom<char>> FromInput for Grid<T> {
+    fn from_input(input: &str) -> Result<Self, Error> {
+        let mut width = None;
+
+        let rows = crate::parse_lines(input, |line| {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(x, chr)| {
+                    T::try_from(chr)
+                        .map_err(|_| Error::at(format!("Invalid character: {}", chr), x + 1, 1))
+                })
+                .collect::<Result<Vec<T>, Error>>()?;
+
+            let expected = *width.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(Error::at(
+                    format!("Expected {} characters, found {}", expected, row.len()),
+                    1,
+                    row.len(),
+                ));
+            }
+
+            Ok(row)
+        })?;
+
+        Ok(Grid::new(rows).expect("rows are checked to be of equal length"))
+    }
+}
+
+macro_rules! impl_from_input_tuple {
+    ($count:literal; $($name:ident),+) => {
+        /// Blocks separated by blank lines, one per element.
+        impl<$($name: FromInput),+> FromInput for ($($name,)+) {
+            fn from_input(input: &str) -> Result<Self, Error> {
+                let found = sections(input).count();
+                if found != $count {
+                    return Err(Error::new(format!(
+                        "Expected {} sections separated by blank lines, found {}",
+                        $count, found
+                    )));
+                }
+
+                let mut sections = sections(input);
+                Ok(($(sections.parse_next::<$name>()?,)+))
+            }
+        }
+    };
+}
+
+impl_from_input_tuple!(2; A, B);
+impl_from_input_tuple!(3; A, B, C);
+
+#[cfg(test)]
+mod tests {
+    use crate::datastructures::grid::Grid;
+    use crate::parse::{Comma, parse};
+
+    #[test]
+    fn test_vec() {
+        let numbers: Vec<i64> = parse("1\n-2\n3\n").unwrap();
+        assert_eq!(numbers, vec![1, -2, 3]);
+    }
+
+    #[test]
+    fn test_vec_error_location() {
+        let error = parse::<Vec<i64>>("1\n2x\n3").unwrap_err();
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.column(), Some(1));
+    }
+
+    #[test]
+    fn test_comma_over_lines() {
+        let Comma(values) = parse::<Comma<u32>>("1,2,\n3, 4\n").unwrap();
+        assert_eq!(values, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_comma_error_location() {
+        let error = parse::<Comma<u32>>("1,2,\n3,x4\n").unwrap_err();
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.column(), Some(3));
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let grid: Grid<char> = parse("ab\ncd\n").unwrap();
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(0, 1), Some(&'c'));
+    }
+
+    #[test]
+    fn test_char_grid_not_rectangular() {
+        let error = parse::<Grid<char>>("ab\nc\n").unwrap_err();
+        assert_eq!(error.line(), Some(2));
+    }
+
+    #[test]
+    fn test_sections() {
+        let (ranges, ids): (Vec<String>, Vec<i64>) = parse("3-5\n10-14\n\n1\n5\n").unwrap();
+        assert_eq!(ranges, vec!["3-5", "10-14"]);
+        assert_eq!(ids, vec![1, 5]);
+    }
+
+    #[test]
+    fn test_sections_error_line_offset() {
+        let error = parse::<(Vec<String>, Vec<i64>)>("3-5\n10-14\n\n1\nx\n").unwrap_err();
+        assert_eq!(error.line(), Some(5));
+    }
+
+    #[test]
+    fn test_sections_wrong_count() {
+        assert!(parse::<(Vec<i64>, Vec<i64>)>("1\n2\n").is_err());
+    }
+}
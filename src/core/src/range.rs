@@ -0,0 +1,153 @@
+use crate::datastructures::interval_tree::IntervalTree;
+use crate::error::Error;
+use std::str::FromStr;
+
+/// Inclusive integer range as written in puzzle inputs.
+///
+/// Understands `a-b` and `a..=b` (both inclusive) and `a..b` (exclusive end), with optional signs
+/// and whitespace around the bounds, e.g. `-5--3` or `-5 ..= -3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangeSpec {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl RangeSpec {
+    pub fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Finds the separator, skipping a `-` that is the sign of the start.
+    fn split(text: &str) -> Option<(&str, &str, &str)> {
+        for separator in ["..=", ".."] {
+            if let Some((start, end)) = text.split_once(separator) {
+                return Some((start, separator, end));
+            }
+        }
+
+        text.char_indices()
+            .skip(1)
+            .find(|&(idx, chr)| {
+                chr == '-'
+                    && text[..idx]
+                        .trim_end()
+                        .ends_with(|c: char| c.is_ascii_digit())
+            })
+            .map(|(idx, _)| (&text[..idx], "-", &text[idx + 1..]))
+    }
+
+    fn bound(line: &str, text: &str, name: &str) -> Result<i64, Error> {
+        let trimmed = text.trim();
+        let part = if trimmed.is_empty() { text } else { trimmed };
+
+        trimmed.parse::<i64>().map_err(|_| {
+            Error::at_slice(format!("Invalid range {}: `{}`", name, trimmed), line, part)
+        })
+    }
+}
+
+impl FromStr for RangeSpec {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let Some((start, separator, end)) = Self::split(line) else {
+            return Err(Error::at_slice(
+                format!(
+                    "Invalid range: `{}`, expected a-b, a..b or a..=b",
+                    line.trim()
+                ),
+                line,
+                line,
+            ));
+        };
+
+        let start = Self::bound(line, start, "start")?;
+        let mut end = Self::bound(line, end, "end")?;
+
+        if separator == ".." {
+            if end <= start {
+                return Err(Error::at_slice(
+                    format!("Empty range: `{}`", line.trim()),
+                    line,
+                    line,
+                ));
+            }
+            end -= 1;
+        } else if start > end {
+            return Err(Error::at_slice(
+                format!("Inverted range: `{}`, start is after end", line.trim()),
+                line,
+                line,
+            ));
+        }
+
+        Ok(Self { start, end })
+    }
+}
+
+impl From<RangeSpec> for (i64, i64) {
+    fn from(range: RangeSpec) -> Self {
+        (range.start, range.end)
+    }
+}
+
+impl FromIterator<RangeSpec> for IntervalTree {
+    fn from_iter<I: IntoIterator<Item = RangeSpec>>(iter: I) -> Self {
+        let intervals: Vec<(i64, i64)> = iter.into_iter().map(<(i64, i64)>::from).collect();
+        IntervalTree::new(&intervals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::range::RangeSpec;
+
+    fn range(text: &str) -> (i64, i64) {
+        text.parse::<RangeSpec>().unwrap().into()
+    }
+
+    #[test]
+    fn test_range_formats() {
+        assert_eq!(range("3-5"), (3, 5));
+        assert_eq!(range("3..=5"), (3, 5));
+        assert_eq!(range("3..6"), (3, 5));
+        assert_eq!(range(" 3 - 5 "), (3, 5));
+    }
+
+    #[test]
+    fn test_range_negative() {
+        assert_eq!(range("-5--3"), (-5, -3));
+        assert_eq!(range("-5 - -3"), (-5, -3));
+        assert_eq!(range("-5..=-3"), (-5, -3));
+        assert_eq!(range("-5-3"), (-5, 3));
+    }
+
+    #[test]
+    fn test_range_errors() {
+        let error = "3-x5".parse::<RangeSpec>().unwrap_err();
+        assert_eq!(error.message(), "Invalid range end: `x5`");
+        assert_eq!(error.column(), Some(3));
+
+        assert!("35".parse::<RangeSpec>().is_err());
+        assert!("3-".parse::<RangeSpec>().is_err());
+        assert!("5..5".parse::<RangeSpec>().is_err());
+    }
+
+    #[test]
+    fn test_range_inverted() {
+        assert_eq!(range("3-3"), (3, 3));
+
+        let error = "10-3".parse::<RangeSpec>().unwrap_err();
+        assert_eq!(
+            error.message(),
+            "Inverted range: `10-3`, start is after end"
+        );
+        assert_eq!(error.column(), Some(1));
+        assert!("10..=3".parse::<RangeSpec>().is_err());
+        assert!("-3--5".parse::<RangeSpec>().is_err());
+    }
+}
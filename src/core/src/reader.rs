@@ -0,0 +1,152 @@
+use crate::error::Error;
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+const BOM: char = '\u{feff}';
+
+/// Reads everything from `reader`, dropping a leading UTF-8 BOM and turning CRLF line endings
+/// into LF, so input saved on Windows parses the same as everywhere else.
+pub fn read_to_string(mut reader: impl Read) -> Result<String, Error> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+    Ok(normalize(&text))
+}
+
+pub fn read_stdin() -> Result<String, Error> {
+    read_to_string(io::stdin().lock())
+}
+
+pub fn normalize(text: &str) -> String {
+    text.strip_prefix(BOM).unwrap_or(text).replace("\r\n", "\n")
+}
+
+/// Line iterator that, unlike [`BufRead::lines`] with `map_while(Result::ok)`, reports I/O errors
+/// with their line number instead of silently ending early. Line endings and the BOM are removed.
+pub struct InputLines<R> {
+    reader: R,
+    line: usize,
+    done: bool,
+}
+
+impl<R: BufRead> InputLines<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: 0,
+            done: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for InputLines<R> {
+    type Item = Result<String, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut buffer = String::new();
+        self.line += 1;
+
+        match self.reader.read_line(&mut buffer) {
+            Ok(0) => {
+                self.done = true;
+                None
+            }
+            Ok(_) => {
+                if buffer.ends_with('\n') {
+                    buffer.pop();
+                    if buffer.ends_with('\r') {
+                        buffer.pop();
+                    }
+                }
+                if self.line == 1 && buffer.starts_with(BOM) {
+                    buffer.remove(0);
+                }
+                Some(Ok(buffer))
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(Error::new(format!(
+                    "Could not read line {}: {}",
+                    self.line, e
+                ))))
+            }
+        }
+    }
+}
+
+pub fn lines<R: Read>(reader: R) -> InputLines<BufReader<R>> {
+    InputLines::new(BufReader::new(reader))
+}
+
+pub fn stdin_lines() -> InputLines<io::StdinLock<'static>> {
+    InputLines::new(io::stdin().lock())
+}
+
+pub fn read_lines(path: &str) -> Result<InputLines<BufReader<File>>, Error> {
+    let file = File::open(path).map_err(|e| Error::from(e).in_file(Path::new(path)))?;
+    Ok(InputLines::new(BufReader::new(file)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reader::{lines, read_to_string};
+    use std::io;
+    use std::io::Read;
+
+    struct FailingReader {
+        data: &'static [u8],
+    }
+
+    impl Read for FailingReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.data.is_empty() {
+                return Err(io::Error::other("disk on fire"));
+            }
+            let count = self.data.len().min(buf.len());
+            buf[..count].copy_from_slice(&self.data[..count]);
+            self.data = &self.data[count..];
+            Ok(count)
+        }
+    }
+
+    #[test]
+    fn test_crlf_and_bom() {
+        let text = read_to_string("\u{feff}L68\r\nL30\r\n".as_bytes()).unwrap();
+        assert_eq!(text, "L68\nL30\n");
+    }
+
+    #[test]
+    fn test_lines_crlf_bom_and_missing_newline() {
+        let result: Vec<String> = lines("\u{feff}L68\r\nL30\r\nR48".as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(result, vec!["L68", "L30", "R48"]);
+    }
+
+    #[test]
+    fn test_lines_keep_empty_lines() {
+        let result: Vec<String> = lines("3-5\n\n1\n".as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(result, vec!["3-5", "", "1"]);
+    }
+
+    #[test]
+    fn test_lines_report_io_error() {
+        let mut iter = lines(FailingReader { data: b"L68\n" });
+        assert_eq!(iter.next().unwrap().unwrap(), "L68");
+        let error = iter.next().unwrap().unwrap_err();
+        assert_eq!(error.message(), "Could not read line 2: disk on fire");
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_read_to_string_reports_invalid_utf8() {
+        assert!(read_to_string(&[0x4c, 0xff, 0x0a][..]).is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use crate::error::Error;
+use std::str::FromStr;
+
+/// Extracts typed fields from a line following a pattern, where every `{}` stands for one field
+/// and everything else has to match literally.
+///
+/// ```
+/// let (start, end) = core::scan!("{}-{}", "-3-14" => i64, i64).unwrap();
+/// assert_eq!((start, end), (-3, 14));
+/// ```
+///
+/// A field is never empty and ends at the first occurrence of the literal text following it, so
+/// `{}-{}` reads `-3-14` as `-3` and `14`. Two placeholders in a row split off a single character,
+/// e.g. `{}{}` reads `L68` as `L` and `68`. Errors point at the offending part of the line.
+#[macro_export]
+macro_rules! scan {
+    ($pattern:expr, $line:expr => $($ty:ty),+ $(,)?) => {{
+        let line: &str = $line;
+        (|| -> ::std::result::Result<_, $crate::Error> {
+            let fields = $crate::scan::fields($pattern, line)?;
+            let mut fields = fields.into_iter();
+            Ok(($($crate::scan::field::<$ty>(line, fields.next().expect(
+                "scan! needs one type per placeholder",
+            ))?,)+))
+        })()
+    }};
+}
+
+/// Splits `line` into the parts matching the placeholders of `pattern`, see [`scan!`].
+pub fn fields<'a>(pattern: &str, line: &'a str) -> Result<Vec<&'a str>, Error> {
+    let literals: Vec<&str> = pattern.split("{}").collect();
+    let mut fields: Vec<&'a str> = vec![];
+
+    if !line.starts_with(literals[0]) {
+        return Err(expected(literals[0], pattern, line, 0));
+    }
+    let mut position = literals[0].len();
+
+    for (idx, &literal) in literals.iter().enumerate().skip(1) {
+        let rest = &line[position..];
+        let Some(first) = rest.chars().next() else {
+            return Err(Error::at(
+                format!("Missing value, expected pattern `{}`", pattern),
+                column(line, position),
+                1,
+            ));
+        };
+
+        let length = if idx == literals.len() - 1 {
+            match rest.strip_suffix(literal) {
+                Some(field) if !field.is_empty() => field.len(),
+                _ => return Err(expected(literal, pattern, line, position)),
+            }
+        } else if literal.is_empty() {
+            first.len_utf8()
+        } else {
+            match rest[first.len_utf8()..].find(literal) {
+                Some(found) => first.len_utf8() + found,
+                None => return Err(expected(literal, pattern, line, position)),
+            }
+        };
+
+        fields.push(&rest[..length]);
+        position += length + literal.len();
+    }
+
+    Ok(fields)
+}
+
+/// Parses one field returned by [`fields`], pointing errors without a column at the field.
+pub fn field<T>(line: &str, field: &str) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    field.parse::<T>().map_err(|e| {
+        let error: Error = e.into();
+        let offset = field.as_ptr() as usize - line.as_ptr() as usize;
+
+        match error.column() {
+            Some(_) => error.with_offset(column(line, offset) - 1),
+            None => Error::at_slice(
+                format!("Invalid value `{}`: {}", field, error.message()),
+                line,
+                field,
+            ),
+        }
+    })
+}
+
+fn column(line: &str, offset: usize) -> usize {
+    line[..offset].chars().count() + 1
+}
+
+fn expected(literal: &str, pattern: &str, line: &str, position: usize) -> Error {
+    Error::at(
+        format!("Expected `{}`, pattern is `{}`", literal, pattern),
+        column(line, position),
+        line[position..].chars().count(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+
+    #[test]
+    fn test_scan_fields() {
+        let (x, y, z) = crate::scan!("{},{},{}", "162,-817,812" => i64, i64, i64).unwrap();
+        assert_eq!((x, y, z), (162, -817, 812));
+    }
+
+    #[test]
+    fn test_scan_adjacent_placeholders() {
+        let (direction, steps) = crate::scan!("{}{}", "L68" => char, u32).unwrap();
+        assert_eq!((direction, steps), ('L', 68));
+    }
+
+    #[test]
+    fn test_scan_literals_around() {
+        let (x, y) = crate::scan!("<{}, {}>", "<-1, 20>" => i32, i32).unwrap();
+        assert_eq!((x, y), (-1, 20));
+        assert!(crate::scan!("<{}, {}>", "<-1, 20" => i32, i32).is_err());
+    }
+
+    #[test]
+    fn test_scan_invalid_field_column() {
+        let error: Error = crate::scan!("{},{},{}", "162,8x7,812" => i64, i64, i64).unwrap_err();
+        assert_eq!(error.column(), Some(5));
+        assert!(error.message().starts_with("Invalid value `8x7`"));
+    }
+
+    #[test]
+    fn test_scan_missing_separator() {
+        let error: Error = crate::scan!("{}-{}", "35" => u64, u64).unwrap_err();
+        assert_eq!(error.message(), "Expected `-`, pattern is `{}-{}`");
+        assert_eq!(error.column(), Some(1));
+    }
+}
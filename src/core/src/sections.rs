@@ -0,0 +1,165 @@
+use crate::error::Error;
+use crate::parse::FromInput;
+use std::str::Lines;
+
+/// One block of a puzzle input, i.e. consecutive non-blank lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    index: usize,
+    line_offset: usize,
+    text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    /// 1-based position of the section in the input.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// 1-based line number of the first line of the section.
+    pub fn first_line(&self) -> usize {
+        self.line_offset + 1
+    }
+
+    /// Text of the section without the surrounding blank lines and without a trailing newline.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn lines(&self) -> Lines<'a> {
+        self.text.lines()
+    }
+
+    /// Parses the section on its own. Line numbers in the error refer to the whole input and the
+    /// message names the section.
+    pub fn parse<T: FromInput>(&self) -> Result<T, Error> {
+        T::from_input(self.text).map_err(|e| self.locate(e))
+    }
+
+    /// Fixes up an error raised while parsing this section's text.
+    pub fn locate(&self, error: Error) -> Error {
+        error
+            .with_line_offset(self.line_offset)
+            .context(format!("Section {}", self.index))
+    }
+}
+
+/// Iterator over the blank line separated sections of an input, see [`sections`].
+pub struct Sections<'a> {
+    input: &'a str,
+    position: usize,
+    line: usize,
+    index: usize,
+}
+
+impl<'a> Sections<'a> {
+    /// Next section parsed as `T`, or an error naming the missing section.
+    pub fn parse_next<T: FromInput>(&mut self) -> Result<T, Error> {
+        let expected = self.index + 1;
+        self.next()
+            .ok_or_else(|| Error::new(format!("Missing section {}", expected)))?
+            .parse()
+    }
+
+    /// Fails if there are sections left, for inputs with a fixed number of sections.
+    pub fn finish(mut self) -> Result<(), Error> {
+        match self.next() {
+            None => Ok(()),
+            Some(section) => Err(Error::new(format!(
+                "Unexpected section {} starting at line {}",
+                section.index(),
+                section.first_line()
+            ))),
+        }
+    }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = Section<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut start: Option<(usize, usize)> = None;
+        let mut end = self.position;
+
+        for line in self.input[self.position..].split_inclusive('\n') {
+            let line_start = self.position;
+            self.position += line.len();
+            self.line += 1;
+
+            if line.trim().is_empty() {
+                if start.is_some() {
+                    break;
+                }
+                continue;
+            }
+
+            start.get_or_insert((line_start, self.line - 1));
+            end = line_start + line.trim_end_matches(['\n', '\r']).len();
+        }
+
+        let (start, line_offset) = start?;
+        self.index += 1;
+
+        Some(Section {
+            index: self.index,
+            line_offset,
+            text: &self.input[start..end],
+        })
+    }
+}
+
+/// Splits the input at blank lines. Several blank lines in a row count as one separator and
+/// blank lines at the start or end are ignored.
+pub fn sections(input: &str) -> Sections<'_> {
+    Sections {
+        input,
+        position: 0,
+        line: 0,
+        index: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sections::sections;
+
+    #[test]
+    fn test_sections_text_and_lines() {
+        let found: Vec<(usize, usize, &str)> = sections("\n3-5\n10-14\n\n\n1\n5\n")
+            .map(|section| (section.index(), section.first_line(), section.text()))
+            .collect();
+
+        assert_eq!(found, vec![(1, 2, "3-5\n10-14"), (2, 6, "1\n5")]);
+    }
+
+    #[test]
+    fn test_section_crlf() {
+        let found: Vec<&str> = sections("a\r\nb\r\n\r\nc").map(|s| s.text()).collect();
+        assert_eq!(found, vec!["a\r\nb", "c"]);
+    }
+
+    #[test]
+    fn test_section_parse_error_names_section() {
+        let mut sections = sections("1\n2\n\n3\nx\n");
+        let first: Vec<i64> = sections.parse_next().unwrap();
+        let error = sections.parse_next::<Vec<i64>>().unwrap_err();
+
+        assert_eq!(first, vec![1, 2]);
+        assert_eq!(error.line(), Some(5));
+        assert!(error.message().starts_with("Section 2: "));
+    }
+
+    #[test]
+    fn test_missing_and_extra_sections() {
+        let mut parts = sections("1\n\n2\n");
+        parts.next();
+        assert!(parts.finish().is_err());
+
+        let mut parts = sections("1\n");
+        parts.next();
+        assert_eq!(
+            parts.parse_next::<Vec<i64>>().unwrap_err().message(),
+            "Missing section 2"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use crate::bench;
+use crate::bench::{BenchConfig, BenchReport};
+use crate::error::Error;
+use crate::reader;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::str::FromStr;
+
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn parse(str: &str) -> Result<Part, String> {
+        match str.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part: {}, expected 1 or 2", str)),
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        Part::parse(str)
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Named values that change how a part is solved, like the number of connections day 8 makes,
+/// which is smaller for the example than for the real input.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Params {
+    values: HashMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(name.into(), value.into());
+    }
+
+    /// Adds all values of `other`, replacing those already set.
+    pub fn extend(&mut self, other: &Params) {
+        self.values.extend(other.values.clone());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Value of `name` parsed as `T`, or `default` if it is not set.
+    pub fn get_or<T>(&self, name: &str, default: T) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: Into<Error>,
+    {
+        match self.values.get(name) {
+            Some(value) => value.parse::<T>().map_err(|e| {
+                e.into()
+                    .context(format!("Invalid parameter {} = {}", name, value))
+            }),
+            None => Ok(default),
+        }
+    }
+}
+
+/// A single day's puzzle: the raw input is parsed once and then handed to both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    fn parse_reader(reader: impl Read) -> Result<Self::Input, Error> {
+        Self::parse(&reader::read_to_string(reader)?)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display;
+
+    fn part2(input: &Self::Input) -> impl Display;
+
+    /// Like [`Solution::part1`], for days whose answer depends on [`Params`].
+    fn part1_with(input: &Self::Input, params: &Params) -> Result<impl Display, Error> {
+        let _ = params;
+        Ok(Self::part1(input))
+    }
+
+    /// Like [`Solution::part2`], for days whose answer depends on [`Params`].
+    fn part2_with(input: &Self::Input, params: &Params) -> Result<impl Display, Error> {
+        let _ = params;
+        Ok(Self::part2(input))
+    }
+}
+
+/// Object safe view on a [`Solution`], so different days can be stored side by side and
+/// dispatched by day number.
+pub trait Runner: Sync {
+    fn day(&self) -> u8;
+
+    fn solve(&self, input: &str, part: Part) -> Result<String, Error> {
+        self.solve_with(input, part, &Params::new())
+    }
+
+    fn solve_with(&self, input: &str, part: Part, params: &Params) -> Result<String, Error>;
+
+    fn bench(&self, input: &str, config: &BenchConfig) -> Result<BenchReport, Error> {
+        self.bench_with(input, config, &[Params::new(), Params::new()])
+    }
+
+    /// Like [`Runner::bench`], with the [`Params`] of part 1 and part 2.
+    fn bench_with(
+        &self,
+        input: &str,
+        config: &BenchConfig,
+        params: &[Params; 2],
+    ) -> Result<BenchReport, Error>;
+}
+
+impl<S: Solution + Sync> Runner for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve_with(&self, input: &str, part: Part, params: &Params) -> Result<String, Error> {
+        let parsed = S::parse(input)?;
+        let answer = match part {
+            Part::One => S::part1_with(&parsed, params)?.to_string(),
+            Part::Two => S::part2_with(&parsed, params)?.to_string(),
+        };
+        Ok(answer)
+    }
+
+    fn bench_with(
+        &self,
+        input: &str,
+        config: &BenchConfig,
+        params: &[Params; 2],
+    ) -> Result<BenchReport, Error> {
+        bench::bench_with::<S>(input, config, params)
+    }
+}
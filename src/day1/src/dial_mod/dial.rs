@@ -1,14 +1,14 @@
 use crate::dial_mod::direction::Direction;
 use crate::dial_mod::instruction::Instruction;
 
-pub(crate) struct Dial {
-    pub(crate) position: u8,
-    pub(crate) zero_hits: u32,
-    pub(crate) zero_wraps: u32,
+pub struct Dial {
+    pub position: u8,
+    pub zero_hits: u32,
+    pub zero_wraps: u32,
 }
 
 impl Dial {
-    pub(crate) fn new(init_pos: u8) -> Self {
+    pub fn new(init_pos: u8) -> Self {
         Dial {
             position: init_pos,
             zero_hits: 0,
@@ -16,7 +16,7 @@ impl Dial {
         }
     }
 
-    pub(crate) fn turn(&mut self, instruction: &Instruction) {
+    pub fn turn(&mut self, instruction: &Instruction) {
         let steps = match instruction.direction {
             Direction::Left => -(instruction.steps as i32),
             Direction::Right => instruction.steps as i32,
@@ -1,5 +1,24 @@
+use core::Error;
+use std::str::FromStr;
+
 #[derive(Debug, PartialEq)]
-pub(crate) enum Direction {
+pub enum Direction {
     Left,
     Right,
 }
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(direction_str: &str) -> Result<Self, Self::Err> {
+        match direction_str {
+            "R" => Ok(Direction::Right),
+            "L" => Ok(Direction::Left),
+            other => Err(Error::at(
+                format!("Invalid direction: {}, expected L or R", other),
+                1,
+                other.chars().count(),
+            )),
+        }
+    }
+}
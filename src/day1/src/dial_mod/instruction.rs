@@ -1,24 +1,24 @@
 use crate::dial_mod::direction::Direction;
+use core::Error;
+use std::str::FromStr;
 
-pub(crate) struct Instruction {
-    pub(crate) direction: Direction,
-    pub(crate) steps: u32,
+pub struct Instruction {
+    pub direction: Direction,
+    pub steps: u32,
 }
 
 impl Instruction {
-    pub(crate) fn parse(instruction_str: &str) -> Option<Instruction> {
-        if instruction_str.len() < 2 {
-            return None;
-        }
+    pub fn parse(instruction_str: &str) -> Result<Instruction, Error> {
+        let (direction, steps) = core::scan!("{}{}", instruction_str => Direction, u32)?;
 
-        let direction = match &instruction_str[0..1] {
-            "R" => Direction::Right,
-            "L" => Direction::Left,
-            _ => return None,
-        };
+        Ok(Instruction { direction, steps })
+    }
+}
 
-        let steps = instruction_str[1..].parse::<u32>().ok()?;
+impl FromStr for Instruction {
+    type Err = Error;
 
-        Some(Instruction { direction, steps })
+    fn from_str(instruction_str: &str) -> Result<Self, Self::Err> {
+        Instruction::parse(instruction_str)
     }
 }
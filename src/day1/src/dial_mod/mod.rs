@@ -1,3 +1,3 @@
-pub(crate) mod dial;
-pub(crate) mod direction;
-pub(crate) mod instruction;
+pub mod dial;
+pub mod direction;
+pub mod instruction;
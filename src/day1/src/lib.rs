@@ -0,0 +1,51 @@
+pub mod dial_mod;
+#[cfg(test)]
+mod tests;
+
+use crate::dial_mod::dial::Dial;
+use crate::dial_mod::instruction::Instruction;
+use core::Error;
+use core::solution::Solution;
+use std::fmt::Display;
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, Error> {
+    core::parse(input)
+}
+
+pub fn turn_all(instructions: &[Instruction]) -> Dial {
+    let mut dial = Dial::new(50);
+
+    for instruction in instructions {
+        dial.turn(instruction);
+    }
+
+    dial
+}
+
+pub fn part1(instructions: &[Instruction]) -> u32 {
+    turn_all(instructions).zero_hits
+}
+
+pub fn part2(instructions: &[Instruction]) -> u32 {
+    turn_all(instructions).zero_wraps
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
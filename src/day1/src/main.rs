@@ -1,23 +1,11 @@
-mod dial_mod;
-mod tests;
-use crate::dial_mod::dial::Dial;
-use crate::dial_mod::instruction::Instruction;
+use core::output::Output;
+use core::solution::Part;
 
 fn main() {
-    const PATH: &str = "src/day1/input.txt";
-    let lines = core::read_lines(PATH).unwrap();
+    let output = Output::from_env_or_exit(1);
+    let instructions = output.load_or_exit(day1::parse);
 
-    let instructions: Vec<_> = lines
-        .map_while(Result::ok)
-        .map(|x| Instruction::parse(&x).expect("Failed to parse instruction"))
-        .collect();
-
-    let mut dial = Dial::new(50);
-
-    for instruction in instructions {
-        dial.turn(&instruction);
-    }
-
-    println!("Zeros: {}", dial.zero_hits);
-    println!("Zero wraps: {}", dial.zero_wraps);
+    output.part(Part::One, "Zeros: ", || day1::part1(&instructions));
+    output.part(Part::Two, "Zero wraps: ", || day1::part2(&instructions));
+    output.finish();
 }
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests;
+
+use core::parse::Comma;
+use core::solution::Solution;
+use core::{Error, RangeSpec};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/*fn is_invalid_id(id: u64) -> bool {
+    let mut repeating_num: Vec<u8> = vec![];
+    let mut read_in_buffer: Vec<u8> = vec![];
+    let mut index: usize = 0;
+
+    let mut id_copy = id.clone();
+    loop {
+        let digit = (id_copy % 10) as u8;
+        id_copy /= 10;
+
+        if repeating_num.len() > 0 && digit == repeating_num[index] {
+            index += 1;
+            read_in_buffer.push(digit);
+        } else if read_in_buffer.len() > 0 {
+            repeating_num.append(&mut read_in_buffer);
+            repeating_num.push(digit);
+            read_in_buffer = vec![];
+            index = 0;
+        } else {
+            repeating_num.push(digit);
+        }
+
+        if index == repeating_num.len() && id_copy == 0 {
+            return true;
+        }
+
+        if index == repeating_num.len() && id_copy != 0 {
+            repeating_num.append(&mut read_in_buffer);
+            read_in_buffer = vec![];
+            index = 0
+        }
+
+        if id_copy == 0 {
+           return false;
+        }
+    }
+}*/
+
+pub fn matches_pattern(pattern: &str, string: &str) -> bool {
+    let pattern_char = pattern.chars().collect::<Vec<char>>();
+    let string_chars = string.chars().collect::<Vec<char>>();
+
+    for i in 0..string_chars.len() {
+        if string_chars[i] != pattern_char[i % pattern_char.len()] {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub fn is_invalid_id_part1(id: u64) -> bool {
+    let s = id.to_string();
+    let len = s.len();
+
+    if !len.is_multiple_of(2) {
+        return false;
+    }
+
+    let (left, right) = s.split_at(len / 2);
+
+    left == right
+}
+
+pub fn is_invalid_id_part2(id: u64) -> bool {
+    let s = id.to_string();
+    let len = s.len();
+
+    for pattern_len in 1..=len / 2 {
+        if !len.is_multiple_of(pattern_len) {
+            continue;
+        }
+
+        let (pattern, _) = s.split_at(pattern_len);
+
+        if matches_pattern(pattern, &s) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Inclusive range of ids, which unlike a [`RangeSpec`] can not be negative.
+struct IdRange(u64, u64);
+
+impl FromStr for IdRange {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let range: RangeSpec = text.parse()?;
+        match (u64::try_from(range.start), u64::try_from(range.end)) {
+            (Ok(start), Ok(end)) => Ok(IdRange(start, end)),
+            _ => Err(Error::at_slice(
+                format!("Ids can not be negative: {}", text.trim()),
+                text,
+                text,
+            )),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<(u64, u64)>, Error> {
+    let Comma(ranges) = core::parse::<Comma<IdRange>>(input)?;
+
+    Ok(ranges
+        .into_iter()
+        .map(|IdRange(start, end)| (start, end))
+        .collect())
+}
+
+pub fn sum_invalid_ids(ranges: &[(u64, u64)], is_invalid: impl Fn(u64) -> bool) -> u64 {
+    let mut result: u64 = 0;
+    for &(start, finish) in ranges {
+        for num in start..finish + 1 {
+            if is_invalid(num) {
+                result += num;
+            }
+        }
+    }
+    result
+}
+
+pub fn part1(ranges: &[(u64, u64)]) -> u64 {
+    sum_invalid_ids(ranges, is_invalid_id_part1)
+}
+
+pub fn part2(ranges: &[(u64, u64)]) -> u64 {
+    sum_invalid_ids(ranges, is_invalid_id_part2)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(u64, u64)>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
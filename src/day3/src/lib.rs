@@ -0,0 +1,220 @@
+use core::Error;
+use core::solution::{Params, Solution};
+use std::fmt::Display;
+
+#[derive(Debug, Clone)]
+pub struct Bank {
+    slots: Vec<BatterySlot>,
+    on_count: usize,
+}
+
+impl Bank {
+    pub fn new(batteries: Vec<Battery>) -> Self {
+        let slots = batteries
+            .into_iter()
+            .map(|battery| BatterySlot::new(false, battery))
+            .collect();
+
+        Self { slots, on_count: 0 }
+    }
+
+    pub fn turn_on(&mut self, turn_on_count: usize) {
+        if self.on_count > 0 {
+            panic!("Bank already on, turn off before turning on");
+        }
+
+        if turn_on_count > self.slots.len() {
+            panic!("Wrong input data!");
+        }
+
+        let mut left_pointer: usize = 0;
+        let mut right_pointer: usize = self.slots.len() - turn_on_count;
+
+        while right_pointer < self.slots.len() {
+            let mut max_joltage: u32 = 0;
+            let mut max_idx = 0;
+            for idx in left_pointer..=right_pointer {
+                let joltage = self.slots[idx].battery.joltage;
+                if joltage > max_joltage {
+                    max_joltage = joltage;
+                    max_idx = idx;
+                }
+            }
+
+            self.slots[max_idx].turn_on();
+            self.on_count += 1;
+            left_pointer = max_idx + 1;
+            right_pointer += 1;
+        }
+    }
+
+    pub fn calculate_joltage(&self) -> u64 {
+        self.slots
+            .iter()
+            .filter(|slot| slot.on)
+            .enumerate()
+            .fold(0, |acc, (idx, slot)| {
+                let multiplier = 10_u64.pow((self.on_count - idx - 1) as u32);
+                acc + (slot.battery.joltage as u64 * multiplier)
+            })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BatterySlot {
+    pub on: bool,
+    pub battery: Battery,
+}
+
+impl BatterySlot {
+    pub fn new(on: bool, battery: Battery) -> Self {
+        Self { on, battery }
+    }
+
+    pub fn turn_on(&mut self) {
+        self.on = true;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Battery {
+    pub joltage: u32,
+}
+
+impl Battery {
+    pub fn new(joltage: u32) -> Self {
+        Battery { joltage }
+    }
+}
+
+/// Parses one bank of batteries per line, one digit per battery.
+pub fn parse(input: &str) -> Result<Vec<Bank>, Error> {
+    core::parse_lines(input, |line| {
+        let batteries = line
+            .chars()
+            .enumerate()
+            .map(|(x, chr)| {
+                chr.to_digit(10)
+                    .map(Battery::new)
+                    .ok_or_else(|| Error::at(format!("Invalid joltage: {}", chr), x + 1, 1))
+            })
+            .collect::<Result<Vec<Battery>, Error>>()?;
+
+        Ok(Bank::new(batteries))
+    })
+}
+
+pub fn total_joltage(banks: &[Bank], turn_on_count: usize) -> u64 {
+    let mut banks = banks.to_vec();
+
+    banks.iter_mut().for_each(|x| x.turn_on(turn_on_count));
+
+    banks
+        .iter()
+        .fold(0, |acc, bank| acc + bank.calculate_joltage())
+}
+
+/// Like [`total_joltage`], but fails instead of panicking if a bank is too short, e.g. for a
+/// `batteries` parameter larger than the puzzle asks for.
+pub fn checked_total_joltage(banks: &[Bank], turn_on_count: usize) -> Result<u64, Error> {
+    if let Some((idx, bank)) = banks
+        .iter()
+        .enumerate()
+        .find(|(_, bank)| bank.slots.len() < turn_on_count)
+    {
+        return Err(Error::new(format!(
+            "Bank {} has {} batteries, cannot turn on {}",
+            idx + 1,
+            bank.slots.len(),
+            turn_on_count
+        )));
+    }
+
+    Ok(total_joltage(banks, turn_on_count))
+}
+
+/// Batteries turned on per bank, can be changed with the `batteries` parameter.
+pub const PART1_BATTERIES: usize = 2;
+pub const PART2_BATTERIES: usize = 12;
+
+pub fn part1(banks: &[Bank]) -> u64 {
+    total_joltage(banks, PART1_BATTERIES)
+}
+
+pub fn part2(banks: &[Bank]) -> u64 {
+    total_joltage(banks, PART2_BATTERIES)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Bank>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+
+    fn part1_with(input: &Self::Input, params: &Params) -> Result<impl Display, Error> {
+        checked_total_joltage(input, params.get_or("batteries", PART1_BATTERIES)?)
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> Result<impl Display, Error> {
+        checked_total_joltage(input, params.get_or("batteries", PART2_BATTERIES)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{checked_total_joltage, parse, total_joltage};
+    use core::input::{InputKind, InputLocator};
+
+    fn run(kind: InputKind, turn_on_count: usize) -> u64 {
+        let input = InputLocator::new(3).read(kind).unwrap();
+        total_joltage(&parse(&input.text).unwrap(), turn_on_count)
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let total_joltage = run(InputKind::Example, 2);
+        assert_eq!(total_joltage, 357);
+    }
+
+    #[test]
+    fn test_part1() {
+        let total_joltage = run(InputKind::Real, 2);
+        assert_eq!(total_joltage, 17_301);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let total_joltage = run(InputKind::Example, 12);
+        assert_eq!(total_joltage, 3121910778619);
+    }
+
+    #[test]
+    fn test_part2() {
+        let total_joltage = run(InputKind::Real, 12);
+        assert_eq!(total_joltage, 172_162_399_742_349);
+    }
+
+    #[test]
+    fn test_invalid_banks() {
+        let error = parse("987654321111111\n98765x321111111").unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(6)));
+
+        let banks = parse("987654321111111\n98765").unwrap();
+        assert_eq!(checked_total_joltage(&banks, 2).unwrap(), 98 + 98);
+        let error = checked_total_joltage(&banks, 12).unwrap_err();
+        assert!(error.message().starts_with("Bank 2 has 5 batteries"));
+    }
+}
@@ -0,0 +1,192 @@
+use core::Error;
+use core::datastructures::grid::Grid as CharGrid;
+use core::math::point2d::Point2D;
+use core::solution::Solution;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// A cell of the input, either a roll of paper or empty floor.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Roll,
+    Empty,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = ();
+
+    fn try_from(chr: char) -> Result<Self, Self::Error> {
+        match chr {
+            '@' => Ok(Cell::Roll),
+            '.' => Ok(Cell::Empty),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Grid {
+    grid: CharGrid<bool>,
+    width: usize,
+    height: usize,
+}
+
+impl Grid {
+    pub fn new(grid: CharGrid<bool>) -> Self {
+        let width = grid.width();
+        let height = grid.height();
+        Self {
+            grid,
+            width,
+            height,
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.grid.get(x, y).copied().unwrap_or(false)
+    }
+
+    pub fn remove(&mut self, x: usize, y: usize) {
+        if let Some(cell) = self.grid.get_mut(x, y) {
+            *cell = false;
+        }
+    }
+
+    pub fn get_adjacent(&self, x: usize, y: usize) -> Vec<bool> {
+        Point2D::new(x as i64, y as i64)
+            .neighbors8_within(self.width, self.height)
+            .map(|point| self.get(point.x() as usize, point.y() as usize))
+            .collect()
+    }
+
+    pub fn is_accessible(&self, x: usize, y: usize) -> bool {
+        self.get_adjacent(x, y).into_iter().filter(|&x| x).count() < 4
+    }
+
+    /// Draws the grid with `x` for marked cells, `@` for the remaining rolls and `.` for empty ones.
+    pub fn render(&self, marked: impl Fn(usize, usize) -> bool) -> String {
+        let mut visual = String::with_capacity((self.width + 1) * self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                visual.push(if marked(x, y) {
+                    'x'
+                } else if self.get(x, y) {
+                    '@'
+                } else {
+                    '.'
+                });
+            }
+            visual.push('\n');
+        }
+        visual
+    }
+
+    pub fn get_accessible_count(&self) -> usize {
+        let mut accessible_count: usize = 0;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let is_roll_of_paper = self.get(x, y);
+                if is_roll_of_paper && self.is_accessible(x, y) {
+                    accessible_count += 1;
+                }
+            }
+        }
+
+        core::trace!(
+            "Accessible rolls:\n{}",
+            self.render(|x, y| self.get(x, y) && self.is_accessible(x, y))
+        );
+        accessible_count
+    }
+
+    pub fn remove_accessible(&mut self) -> usize {
+        let mut removed: HashSet<(usize, usize)> = HashSet::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let is_roll_of_paper = self.get(x, y);
+                if is_roll_of_paper && self.is_accessible(x, y) {
+                    removed.insert((x, y));
+                    self.remove(x, y);
+                }
+            }
+        }
+
+        core::trace!(
+            "Removed {} rolls:\n{}",
+            removed.len(),
+            self.render(|x, y| removed.contains(&(x, y)))
+        );
+        removed.len()
+    }
+
+    pub fn remove_all(&mut self) -> usize {
+        let mut total_removed = 0;
+        loop {
+            let removed_count = self.remove_accessible();
+            total_removed += removed_count;
+
+            if removed_count == 0 {
+                break;
+            }
+        }
+        total_removed
+    }
+}
+
+/// Parses the rectangular grid of `@` rolls and `.` floor.
+pub fn parse(input: &str) -> Result<Grid, Error> {
+    let cells: CharGrid<Cell> = core::parse(input)?;
+
+    if cells.height() == 0 {
+        return Err(Error::new("wrong input, grid is empty"));
+    }
+
+    Ok(Grid::new(cells.map(|cell| cell == Cell::Roll)))
+}
+
+pub fn part1(grid: &Grid) -> usize {
+    grid.get_accessible_count()
+}
+
+pub fn part2(grid: &Grid) -> usize {
+    grid.clone().remove_all()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+
+    #[test]
+    fn test_ragged_rows() {
+        let error = parse("@@@\n@\n").err().unwrap();
+        assert_eq!(error.line(), Some(2));
+        assert!(
+            error
+                .message()
+                .starts_with("Expected 3 characters, found 1")
+        );
+
+        let error = parse("@.@\n@x@\n").err().unwrap();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(2)));
+    }
+}
@@ -0,0 +1,90 @@
+use core::datastructures::interval_tree::IntervalTree;
+use core::solution::Solution;
+use core::{Error, RangeSpec};
+use std::fmt::Display;
+
+pub struct Inventory {
+    pub tree: IntervalTree,
+    pub ids: Vec<i64>,
+}
+
+impl Inventory {
+    pub fn count_fresh(&self) -> u32 {
+        let mut fresh: u32 = 0;
+
+        for &number in &self.ids {
+            if self.tree.contains_including_borders(number) {
+                fresh += 1;
+            }
+        }
+
+        fresh
+    }
+
+    pub fn count_unique_fresh(&self) -> i64 {
+        self.tree.merge().iter().map(|x| x.end - x.start + 1).sum()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Inventory, Error> {
+    let mut sections = core::sections(input);
+    let ranges: Vec<RangeSpec> = sections.parse_next()?;
+    let ids: Vec<i64> = sections.parse_next()?;
+    sections.finish()?;
+
+    let tree = IntervalTree::from_iter(ranges);
+
+    Ok(Inventory { tree, ids })
+}
+
+pub fn part1(inventory: &Inventory) -> u32 {
+    inventory.count_fresh()
+}
+
+pub fn part2(inventory: &Inventory) -> i64 {
+    inventory.count_unique_fresh()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day5, parse, part1, part2};
+    use core::input::{InputKind, InputLocator};
+    use core::solution::Solution;
+    use std::fs::File;
+
+    #[test]
+    fn test_example() {
+        let path = InputLocator::new(5).locate(InputKind::Example).unwrap();
+        let file = File::open(path).unwrap();
+        let inventory = Day5::parse_reader(file).unwrap();
+        assert_eq!(part1(&inventory), 3);
+        assert_eq!(part2(&inventory), 14);
+    }
+
+    #[test]
+    fn test_invalid_id_names_section() {
+        let error = parse("3-5\n10-14\n\n1\n5a\n").err().unwrap();
+        assert_eq!(error.line(), Some(5));
+        assert!(error.message().starts_with("Section 2: "));
+    }
+}
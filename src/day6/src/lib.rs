@@ -0,0 +1,251 @@
+use core::Error;
+use core::solution::Solution;
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Problem {
+    pub numbers: Vec<u64>,
+    pub operation: Option<Operation>,
+}
+
+impl Problem {
+    pub fn new() -> Self {
+        Problem {
+            numbers: vec![],
+            operation: None,
+        }
+    }
+
+    pub fn solve(&self) -> u64 {
+        if self.operation.is_none() {
+            panic!("No operation was added before calling solve")
+        }
+
+        let operation = self.operation.as_ref().unwrap();
+
+        let initial_accumulator = match operation {
+            Operation::Add => 0,
+            Operation::Multiply => 1,
+        };
+
+        self.numbers
+            .iter()
+            .fold(initial_accumulator, |acc, num| operation.apply(acc, *num))
+    }
+
+    pub fn add_number(&mut self, number: u64) {
+        self.numbers.push(number);
+    }
+
+    pub fn add_operation(&mut self, op: Operation) {
+        self.operation = Some(op);
+    }
+}
+
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+impl Operation {
+    pub fn parse_from_symbol(symbol: char) -> Option<Self> {
+        match symbol {
+            '+' => Some(Operation::Add),
+            '*' => Some(Operation::Multiply),
+            _ => None,
+        }
+    }
+
+    pub fn apply(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Operation::Add => a + b,
+            Operation::Multiply => a * b,
+        }
+    }
+}
+
+pub struct Worksheet {
+    pub problems: Vec<Problem>,
+    /// Digits of the number rows by column, `None` for blanks.
+    pub digits: Vec<Vec<Option<u32>>>,
+    /// Operator row by column, `None` for blanks.
+    pub operations: Vec<Option<Operation>>,
+}
+
+pub fn parse(input: &str) -> Result<Worksheet, Error> {
+    let lines: Vec<&str> = input.lines().collect();
+    let Some((operator_line, number_lines)) = lines.split_last() else {
+        return Err(Error::new(
+            "Expected number rows followed by an operator row",
+        ));
+    };
+
+    let width = lines[0].chars().count();
+    if let Some((line_idx, line)) = lines
+        .iter()
+        .enumerate()
+        .find(|(_, line)| line.chars().count() != width)
+    {
+        let length = line.chars().count();
+        return Err(Error::at(
+            format!("Expected {} characters, found {}", width, length),
+            1,
+            length,
+        )
+        .with_line(line_idx + 1, line));
+    }
+
+    let mut digits = vec![];
+    for (line_idx, line) in number_lines.iter().enumerate() {
+        let row = line
+            .chars()
+            .enumerate()
+            .map(|(x, chr)| match chr {
+                ' ' => Ok(None),
+                chr => chr.to_digit(10).map(Some).ok_or_else(|| {
+                    Error::at(format!("Invalid digit: {}", chr), x + 1, 1)
+                        .with_line(line_idx + 1, line)
+                }),
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        digits.push(row);
+    }
+
+    let operations = operator_line
+        .chars()
+        .enumerate()
+        .map(|(x, chr)| match chr {
+            ' ' => Ok(None),
+            chr => Operation::parse_from_symbol(chr).map(Some).ok_or_else(|| {
+                Error::at(format!("Invalid operation: {}", chr), x + 1, 1)
+                    .with_line(lines.len(), operator_line)
+            }),
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut problems: Vec<Problem> = vec![];
+
+    for (line_idx, line) in lines.iter().enumerate() {
+        let parts = line.split_whitespace();
+
+        for (index, part) in parts.enumerate() {
+            if index >= problems.len() {
+                problems.push(Problem::new())
+            }
+
+            let problem = &mut problems[index];
+
+            if part == "*" || part == "+" {
+                let symbol = part.chars().next().unwrap();
+                let operation = Operation::parse_from_symbol(symbol).ok_or_else(|| {
+                    Error::at_slice("Invalid input", line, part).with_line(line_idx + 1, line)
+                })?;
+                problem.add_operation(operation);
+                continue;
+            }
+
+            let number = part.parse::<u64>().map_err(|_| {
+                Error::at_slice(format!("Could not parse number: {}", part), line, part)
+                    .with_line(line_idx + 1, line)
+            })?;
+            problem.add_number(number);
+        }
+    }
+
+    if let Some(idx) = problems
+        .iter()
+        .position(|problem| problem.operation.is_none())
+    {
+        return Err(Error::new(format!("Problem {} has no operation", idx + 1))
+            .with_line(lines.len(), operator_line));
+    }
+
+    Ok(Worksheet {
+        problems,
+        digits,
+        operations,
+    })
+}
+
+pub fn part1(worksheet: &Worksheet) -> u64 {
+    worksheet
+        .problems
+        .iter()
+        .map(|problem| problem.solve())
+        .sum()
+}
+
+pub fn part2(worksheet: &Worksheet) -> u64 {
+    let mut number_stack: Vec<u64> = vec![];
+    let mut total = 0_u64;
+
+    for x in (0..worksheet.operations.len()).rev() {
+        let mut multiplier = 1;
+        let mut num = 0_u32;
+        for row in worksheet.digits.iter().rev() {
+            let Some(digit) = row[x] else {
+                continue;
+            };
+
+            num += multiplier * digit;
+            multiplier *= 10;
+        }
+
+        if num > 0 {
+            number_stack.push(num as u64);
+        }
+
+        let Some(ref operation) = worksheet.operations[x] else {
+            continue;
+        };
+
+        let mut acc = match operation {
+            Operation::Add => 0,
+            Operation::Multiply => 1,
+        };
+
+        while let Some(number) = number_stack.pop() {
+            acc = operation.apply(acc, number);
+        }
+        total += acc;
+    }
+
+    total
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+
+    #[test]
+    fn test_invalid_worksheet() {
+        let error = parse("12 3\n4x 5\n*  +").err().unwrap();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(2)));
+
+        let error = parse("12 3\n4  5\n*  -").err().unwrap();
+        assert_eq!((error.line(), error.column()), (Some(3), Some(4)));
+
+        let error = parse("12 3\n4  5\n*").err().unwrap();
+        assert_eq!(error.line(), Some(3));
+    }
+}
@@ -0,0 +1,241 @@
+use core::Error;
+use core::datastructures::grid::Grid as CharGrid;
+use core::math::direction::Direction;
+use core::math::point2d::Point2D;
+use core::solution::Solution;
+use std::cmp::PartialEq;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Element {
+    Start,
+    Splitter,
+    Beam,
+    Empty,
+}
+
+impl Element {
+    pub fn parse_from_char(chr: char) -> Option<Self> {
+        match chr {
+            'S' => Some(Self::Start),
+            '^' => Some(Self::Splitter),
+            '|' => Some(Self::Beam),
+            '.' => Some(Self::Empty),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(&self) -> char {
+        match self {
+            Self::Start => 'S',
+            Self::Splitter => '^',
+            Self::Beam => '|',
+            Self::Empty => '.',
+        }
+    }
+}
+
+impl TryFrom<char> for Element {
+    type Error = ();
+
+    fn try_from(chr: char) -> Result<Self, Self::Error> {
+        Element::parse_from_char(chr).ok_or(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Grid {
+    data: HashMap<(i64, i64), Element>,
+    width: usize,
+    height: usize,
+}
+
+impl Grid {
+    pub fn new(data: HashMap<(i64, i64), Element>) -> Self {
+        let width = data.keys().map(|(x, _)| *x).max().unwrap_or(0) as usize + 1;
+        let height = data.keys().map(|(_, y)| *y).max().unwrap_or(0) as usize + 1;
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if self.get(&(x as i64, y as i64)).is_none() {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    pub fn get(&self, coordinates: &(i64, i64)) -> Option<Element> {
+        self.data.get(coordinates).copied()
+    }
+
+    pub fn change(&mut self, coordinates: &(i64, i64), element: Element) {
+        if self.data.contains_key(coordinates) {
+            self.data.insert(*coordinates, element);
+        }
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut visual = vec![vec![Element::Empty; self.width]; self.height];
+
+        for (&(x, y), &elem) in &self.data {
+            visual[y as usize][x as usize] = elem;
+        }
+
+        for row in visual {
+            for elem in row {
+                write!(f, "{}", elem.to_char())?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Manifold {
+    pub grid: Grid,
+    pub starts: Vec<(i64, i64)>,
+}
+
+pub struct Simulation {
+    pub grid: Grid,
+    pub split_count: u32,
+    pub timeline_count: u64,
+}
+
+impl Manifold {
+    pub fn simulate(&self) -> Simulation {
+        let mut grid = self.grid.clone();
+        let mut beams: HashMap<(i64, i64), u64> = HashMap::new();
+        let mut split_count: u32 = 0;
+        let mut timeline_count = 0;
+
+        for &x in &self.starts {
+            beams.insert(x, 1);
+        }
+
+        while !beams.is_empty() {
+            let mut next_beams: HashMap<(i64, i64), u64> = HashMap::new();
+
+            for beam in beams {
+                let coordinates = beam.0;
+
+                let down = Point2D::from(coordinates).step(Direction::South);
+                let down_coordinates: (i64, i64) = down.into();
+
+                let elem = grid.get(&down_coordinates);
+
+                if elem.is_none() {
+                    timeline_count += beam.1;
+                    continue;
+                }
+
+                let elem = elem.unwrap();
+
+                match elem {
+                    Element::Empty => {
+                        grid.change(&down_coordinates, Element::Beam);
+                    }
+                    Element::Splitter => {
+                        let splitter_left_coordinates = down.step(Direction::West).into();
+                        let splitter_right_coordinates = down.step(Direction::East).into();
+
+                        next_beams
+                            .entry(splitter_left_coordinates)
+                            .and_modify(|entry| *entry += beam.1)
+                            .or_insert(beam.1);
+
+                        next_beams
+                            .entry(splitter_right_coordinates)
+                            .and_modify(|entry| *entry += beam.1)
+                            .or_insert(beam.1);
+
+                        grid.change(&splitter_left_coordinates, Element::Beam);
+                        grid.change(&splitter_right_coordinates, Element::Beam);
+                        split_count += 1;
+                        continue;
+                    }
+                    Element::Beam => {}
+                    _ => panic!("Hit wrong element, we are doomed!"),
+                }
+
+                next_beams
+                    .entry(down_coordinates)
+                    .and_modify(|entry| *entry += beam.1)
+                    .or_insert(beam.1);
+            }
+            beams = next_beams;
+        }
+
+        Simulation {
+            grid,
+            split_count,
+            timeline_count,
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Manifold, Error> {
+    let cells: CharGrid<Element> = core::parse(input)?;
+
+    let mut starts: Vec<(i64, i64)> = vec![];
+    let mut data: HashMap<(i64, i64), Element> = HashMap::new();
+
+    for ((x, y), &element) in cells.iter() {
+        if element == Element::Start {
+            starts.push((x as i64, y as i64));
+        }
+
+        data.insert((x as i64, y as i64), element);
+    }
+
+    let grid = Grid::new(data);
+
+    if starts.is_empty() {
+        return Err(Error::new(
+            "No start found, check input data and add 'S' symbol somewhere!",
+        ));
+    }
+
+    Ok(Manifold { grid, starts })
+}
+
+pub fn part1(manifold: &Manifold) -> u32 {
+    manifold.simulate().split_count
+}
+
+pub fn part2(manifold: &Manifold) -> u64 {
+    manifold.simulate().timeline_count
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Manifold;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
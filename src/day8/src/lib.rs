@@ -0,0 +1,185 @@
+use core::Error;
+use core::math::metric::{Metric, SquaredEuclidean};
+use core::math::point3d::Point3D;
+use core::solution::{Params, Solution};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Display;
+
+pub struct UnionFind {
+    pub parent: Vec<usize>,
+    pub group_count: usize,
+}
+
+impl UnionFind {
+    pub fn new(size: usize) -> Self {
+        let mut parent = Vec::with_capacity(size);
+
+        for i in 0..size {
+            parent.push(i);
+        }
+
+        Self {
+            parent,
+            group_count: size,
+        }
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    pub fn union(&mut self, x: usize, y: usize) {
+        let rootx = self.find(x);
+        let rooty = self.find(y);
+
+        if rootx != rooty {
+            self.parent[rooty] = rootx;
+            self.group_count -= 1;
+        }
+    }
+}
+
+/// A possible connection, ordered by distance and then by the indices of its points so that
+/// equally long connections are always made in the same order. Distances too large even for a
+/// `u128` are stored as `u128::MAX` and so come last.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DistanceItem {
+    pub distance: u128,
+    pub index_first: usize,
+    pub index_second: usize,
+}
+
+pub fn build_heap<M: Metric>(points: &[Point3D]) -> BinaryHeap<Reverse<DistanceItem>> {
+    let mut heap: BinaryHeap<Reverse<DistanceItem>> = BinaryHeap::new();
+    for idx_outer in 0..points.len() {
+        for idx_inner in idx_outer + 1..points.len() {
+            let distance_item = DistanceItem {
+                distance: M::checked_distance_wide(&points[idx_outer], &points[idx_inner])
+                    .unwrap_or(u128::MAX),
+                index_first: idx_outer,
+                index_second: idx_inner,
+            };
+            heap.push(Reverse(distance_item))
+        }
+    }
+    heap
+}
+
+/// Parses one junction box per line, at least two are needed to make a connection.
+pub fn parse(input: &str) -> Result<Vec<Point3D>, Error> {
+    let points = core::parse_lines(input, Point3D::parse)?;
+    if points.len() < 2 {
+        return Err(Error::new(format!(
+            "Expected at least 2 junction boxes, found {}",
+            points.len()
+        )));
+    }
+
+    Ok(points)
+}
+
+pub fn largest_circuits_product<M: Metric>(points: &[Point3D], connections: usize) -> usize {
+    let mut heap = build_heap::<M>(points);
+    let mut groups: UnionFind = UnionFind::new(points.len());
+
+    // Asking for more connections than there are pairs simply connects every pair
+    for _ in 0..connections {
+        let Some(Reverse(distance_item)) = heap.pop() else {
+            break;
+        };
+
+        groups.union(distance_item.index_first, distance_item.index_second);
+    }
+
+    let mut sizes: HashMap<usize, usize> = HashMap::new();
+    for i in 0..points.len() {
+        let root = groups.find(i);
+        *sizes.entry(root).or_insert(0) += 1;
+    }
+
+    let mut group_sizes: Vec<usize> = sizes.values().cloned().collect();
+    group_sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+    group_sizes.iter().take(3).product()
+}
+
+/// Shortest connections made in part 1, can be changed with the `connections` parameter.
+pub const CONNECTIONS: usize = 1000;
+
+pub fn part1(points: &[Point3D]) -> usize {
+    largest_circuits_product::<SquaredEuclidean>(points, CONNECTIONS)
+}
+
+pub fn part2(points: &[Point3D]) -> i64 {
+    last_connection_product::<SquaredEuclidean>(points)
+}
+
+/// Product of the x coordinates of the two points whose connection joins everything into one
+/// circuit, 0 if there are fewer than two points and so no connection at all.
+pub fn last_connection_product<M: Metric>(points: &[Point3D]) -> i64 {
+    let mut heap = build_heap::<M>(points);
+    let mut groups: UnionFind = UnionFind::new(points.len());
+
+    while let Some(Reverse(distance_item)) = heap.pop() {
+        groups.union(distance_item.index_first, distance_item.index_second);
+
+        if groups.group_count <= 1 {
+            let point1 = &points[distance_item.index_first];
+            let point2 = &points[distance_item.index_second];
+            return point1.x() * point2.x();
+        }
+    }
+
+    0
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Point3D>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+
+    fn part1_with(input: &Self::Input, params: &Params) -> Result<impl Display, Error> {
+        Ok(largest_circuits_product::<SquaredEuclidean>(
+            input,
+            params.get_or("connections", CONNECTIONS)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{largest_circuits_product, last_connection_product, parse};
+    use core::input::{InputKind, InputLocator};
+    use core::math::metric::SquaredEuclidean;
+
+    #[test]
+    fn test_more_connections_than_pairs() {
+        let input = InputLocator::new(8).read(InputKind::Example).unwrap();
+        let points = parse(&input.text).unwrap();
+
+        assert_eq!(
+            largest_circuits_product::<SquaredEuclidean>(&points, 1000),
+            20
+        );
+        assert_eq!(last_connection_product::<SquaredEuclidean>(&points[..1]), 0);
+        assert!(parse("1,2,3").is_err());
+    }
+}
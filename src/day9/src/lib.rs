@@ -0,0 +1,203 @@
+use std::cmp::{max, min};
+use std::fmt::Display;
+use core::math::point2d::Point2D;
+use core::Error;
+use core::solution::Solution;
+
+pub struct VerticalLine{
+    pub x: i64,
+    pub y1: i64,
+    pub y2: i64,
+}
+
+pub struct HorizontalLine {
+    pub y: i64,
+    pub x1: i64,
+    pub x2: i64,
+}
+
+pub struct Floor {
+    pub points: Vec<Point2D>,
+    pub horizontal_lines: Vec<HorizontalLine>,
+    pub vertical_lines: Vec<VerticalLine>,
+}
+
+impl Floor {
+    pub fn area(outer: &Point2D, inner: &Point2D) -> u64 {
+        let vec = outer - inner;
+        let height = vec.y().abs() + 1;
+        let width = vec.x().abs() + 1;
+
+        height as u64 * width as u64
+    }
+
+    pub fn is_crossed(&self, outer: &Point2D, inner: &Point2D) -> bool {
+        let left_border_x = min(outer.x(), inner.x());
+        let right_border_x = max(outer.x(), inner.x());
+        let top_border_y = min(outer.y(), inner.y());
+        let bottom_border_y = max(outer.y(), inner.y());
+
+        let horizontal_crossed = self.horizontal_lines.iter().any(|horizontal_line| {
+            if horizontal_line.y <= top_border_y || bottom_border_y <= horizontal_line.y {
+                return false;
+            }
+
+            if horizontal_line.x1 <= left_border_x && left_border_x < horizontal_line.x2 {
+                return true;
+            }
+
+            if horizontal_line.x1 < right_border_x && right_border_x <= horizontal_line.x2{
+                return true;
+            }
+
+            false
+        });
+
+        if horizontal_crossed {
+            return true;
+        }
+
+        self.vertical_lines.iter().any(|vertical_line| {
+            if vertical_line.x <= left_border_x || right_border_x <= vertical_line.x {
+                return false;
+            }
+
+            if vertical_line.y1 <= top_border_y && top_border_y < vertical_line.y2 {
+                return true;
+            }
+
+            if vertical_line.y1 < bottom_border_y && bottom_border_y <= vertical_line.y2{
+                return true;
+            }
+
+            false
+        })
+    }
+
+    pub fn largest_rectangle(&self) -> u64 {
+        let points = &self.points;
+
+        let mut max_area : u64 = 0;
+        for outer_idx in 0..points.len()-1 {
+            for inner_idx in outer_idx + 1..points.len()-1 {
+                let area = Floor::area(&points[outer_idx], &points[inner_idx]);
+
+                if area > max_area {
+                    max_area = area;
+                }
+            }
+        }
+        max_area
+    }
+
+    pub fn largest_enclosed_rectangle(&self) -> u64 {
+        let points = &self.points;
+
+        let mut max_area : u64 = 0;
+        for outer_idx in 0..points.len()-1 {
+            for inner_idx in outer_idx + 1..points.len()-1 {
+                let outer = &points[outer_idx];
+                let inner = &points[inner_idx];
+
+                let area = Floor::area(outer, inner);
+
+                if area < max_area {
+                    continue;
+                }
+
+                if self.is_crossed(outer, inner) {
+                    continue;
+                }
+
+                max_area = area;
+            }
+        }
+        max_area
+    }
+}
+
+pub fn parse(input: &str) -> Result<Floor, Error> {
+    let mut points = core::parse_lines(input, Point2D::parse)?;
+
+    if points.is_empty() {
+        return Err(Error::new("Can't parse input, no points given!"));
+    }
+
+    let lines: Vec<&str> = input.lines().collect();
+
+    let mut horizontal_lines: Vec<HorizontalLine> = vec![];
+    let mut vertical_lines: Vec<VerticalLine> = vec![];
+
+    points.push(points[0]);
+
+    for (idx, point) in points.windows(2).enumerate() {
+        let first = &point[0];
+        let second = &point[1];
+
+        let min_x = min(first.x(), second.x());
+        let max_x = max(first.x(), second.x());
+        let min_y = min(first.y(), second.y());
+        let max_y = max(first.y(), second.y());
+
+        // This is a vertical line
+        if min_x == max_x {
+            vertical_lines.push(VerticalLine{
+                x: min_x,
+                y1: min_y,
+                y2: max_y
+            });
+            continue;
+        }
+
+        // This is a horizontal line
+        if min_y == max_y {
+            horizontal_lines.push(HorizontalLine{
+                y: min_y,
+                x1: min_x,
+                x2: max_x
+            });
+            continue;
+        }
+
+        // The line ends at the following point, wrapping around to the first one
+        let line_idx = (idx + 1) % lines.len();
+        return Err(
+            Error::new("Can't parse input, line is not horizontal or vertical!")
+                .with_line(line_idx + 1, lines[line_idx]),
+        );
+    }
+
+    Ok(Floor {
+        points,
+        horizontal_lines,
+        vertical_lines,
+    })
+}
+
+pub fn part1(floor: &Floor) -> u64 {
+    floor.largest_rectangle()
+}
+
+pub fn part2(floor: &Floor) -> u64 {
+    floor.largest_enclosed_rectangle()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Floor;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}